@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A single output file this run intends to (re)generate, paired with its
+/// in-memory content. Shared by the normal write path and `--check`, so both
+/// always agree on exactly what would be produced.
+pub struct PlannedFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// Summary of comparing every planned file against what's already on disk.
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub stale: Vec<PathBuf>,
+    pub missing: Vec<PathBuf>,
+    pub extra: Vec<PathBuf>,
+}
+
+impl CheckReport {
+    pub fn is_clean(&self) -> bool {
+        self.stale.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Strip volatile content before comparison: generator version banners,
+/// this run's absolute working directory, and trailing whitespace. Mirrors
+/// the normalization pass cargo's test-support `compare.rs` applies before
+/// diffing golden output, so unrelated noise doesn't show up as drift.
+pub fn normalize(content: &str) -> String {
+    let cwd = std::env::current_dir().ok();
+    content
+        .replace("\r\n", "\n")
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            let line = match &cwd {
+                Some(cwd) => line.replace(cwd.to_string_lossy().as_ref(), "[..]"),
+                None => line.to_string(),
+            };
+            if is_generator_banner(&line) {
+                "[GENERATOR_BANNER]".to_string()
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_generator_banner(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("' Generated by protoc-http-rs") || trimmed.starts_with("// Generated by protoc-http-rs")
+}
+
+/// Compare a normalized on-disk baseline against normalized freshly
+/// generated output, line by line. The baseline may contain `[..]` wildcard
+/// tokens (cargo `compare.rs` style) for hand-maintained expectations whose
+/// exact text isn't worth pinning, e.g. timestamps embedded by a proto
+/// comment.
+pub fn matches_baseline(baseline: &str, actual: &str) -> bool {
+    let baseline_lines: Vec<&str> = baseline.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    baseline_lines.len() == actual_lines.len()
+        && baseline_lines
+            .iter()
+            .zip(actual_lines.iter())
+            .all(|(expected, actual)| line_matches(expected, actual))
+}
+
+fn line_matches(expected: &str, actual: &str) -> bool {
+    if !expected.contains("[..]") {
+        return expected == actual;
+    }
+
+    let parts: Vec<&str> = expected.split("[..]").collect();
+    let mut remaining = actual;
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            if !remaining.starts_with(part) {
+                return false;
+            }
+            remaining = &remaining[part.len()..];
+        } else if i == parts.len() - 1 {
+            return remaining.ends_with(part);
+        } else {
+            match remaining.find(part) {
+                Some(pos) => remaining = &remaining[pos + part.len()..],
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Render a line-by-line diff between the on-disk baseline and freshly
+/// generated output, in a unified-diff-like `-`/`+` format.
+pub fn diff(label: &str, expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut out = vec![format!("--- {} (on disk)", label), format!("+++ {} (generated)", label)];
+
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if line_matches(e, a) => {}
+            (Some(e), Some(a)) => {
+                out.push(format!("-{}", e));
+                out.push(format!("+{}", a));
+            }
+            (Some(e), None) => out.push(format!("-{}", e)),
+            (None, Some(a)) => out.push(format!("+{}", a)),
+            (None, None) => {}
+        }
+    }
+
+    out.join("\n")
+}
+
+/// List files already in `out_dir` whose extension matches a generator's
+/// output (e.g. `vb`, `rs`), used to detect files `--check` considers
+/// "extra": on disk, but no longer produced by any planned file.
+pub fn scan_existing_outputs(out_dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(out_dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some(extension))
+        .collect()
+}
+
+/// Compare every planned file against disk. Returns the report plus a
+/// rendered diff for the first stale file encountered, if any.
+pub fn run(planned: &[PlannedFile], existing_outputs: &[PathBuf]) -> (CheckReport, Option<String>) {
+    let mut report = CheckReport::default();
+    let mut first_diff = None;
+    let planned_paths: HashSet<&Path> = planned.iter().map(|file| file.path.as_path()).collect();
+
+    for file in planned {
+        match std::fs::read_to_string(&file.path) {
+            Ok(on_disk) => {
+                let normalized_disk = normalize(&on_disk);
+                let normalized_generated = normalize(&file.content);
+                if !matches_baseline(&normalized_disk, &normalized_generated) {
+                    report.stale.push(file.path.clone());
+                    if first_diff.is_none() {
+                        first_diff = Some(diff(&file.path.display().to_string(), &normalized_disk, &normalized_generated));
+                    }
+                }
+            }
+            Err(_) => report.missing.push(file.path.clone()),
+        }
+    }
+
+    for existing in existing_outputs {
+        if !planned_paths.contains(existing.as_path()) {
+            report.extra.push(existing.clone());
+        }
+    }
+
+    (report, first_diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_strips_trailing_whitespace() {
+        assert_eq!(normalize("line one   \nline two\t\n"), "line one\nline two");
+    }
+
+    #[test]
+    fn test_normalize_collapses_generator_banner() {
+        let vb = normalize("' Generated by protoc-http-rs v0.1.0\nPublic Class Foo");
+        let rust = normalize("// Generated by protoc-http-rs v0.1.0\npub struct Foo;");
+        assert!(vb.starts_with("[GENERATOR_BANNER]"));
+        assert!(rust.starts_with("[GENERATOR_BANNER]"));
+    }
+
+    #[test]
+    fn test_matches_baseline_exact() {
+        assert!(matches_baseline("a\nb\nc", "a\nb\nc"));
+        assert!(!matches_baseline("a\nb\nc", "a\nx\nc"));
+    }
+
+    #[test]
+    fn test_matches_baseline_wildcard() {
+        assert!(matches_baseline("Hello [..]!", "Hello World!"));
+        assert!(matches_baseline("' Built at [..] by builder", "' Built at 2026-07-29T00:00:00Z by builder"));
+        assert!(!matches_baseline("Hello [..]!", "Hello World?"));
+    }
+
+    #[test]
+    fn test_matches_baseline_different_line_counts() {
+        assert!(!matches_baseline("a\nb", "a\nb\nc"));
+    }
+
+    #[test]
+    fn test_run_reports_missing_and_extra() {
+        let planned = vec![PlannedFile {
+            path: PathBuf::from("/nonexistent/path/does/not/exist.vb"),
+            content: "content".to_string(),
+        }];
+        let existing = vec![PathBuf::from("/nonexistent/path/does/not/exist/OtherFile.vb")];
+        let (report, first_diff) = run(&planned, &existing);
+        assert_eq!(report.missing, vec![PathBuf::from("/nonexistent/path/does/not/exist.vb")]);
+        assert_eq!(report.extra, existing);
+        assert!(first_diff.is_none());
+        assert!(!report.is_clean());
+    }
+}
@@ -0,0 +1,486 @@
+use crate::codegen::CodeGenerator;
+use crate::error::Result;
+use crate::target::CSharp;
+use crate::types::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// C# code generator, mirroring [`crate::vb_codegen::VbNetGenerator`]'s
+/// request/response shape and routes (via the shared
+/// [`crate::target::TargetLanguage`] abstraction) so both backends talk to
+/// the same API. C#'s optional-parameter support lets cancellation collapse
+/// into a single method overload instead of VB.NET's two.
+pub struct CSharpGenerator {
+    namespace: Option<String>,
+    compat_mode: CompatibilityMode,
+}
+
+impl CSharpGenerator {
+    /// Create a new C# generator with optional custom namespace and compatibility mode
+    pub fn new(namespace: Option<String>, compat_mode: CompatibilityMode) -> Self {
+        Self { namespace, compat_mode }
+    }
+
+    /// Generate C# `using` declarations based on compatibility mode
+    fn generate_imports(&self) -> String {
+        let mut imports = vec!["using System;", "using System.Collections.Generic;", "using Newtonsoft.Json;"];
+
+        match self.compat_mode {
+            CompatibilityMode::Net45 => imports.extend([
+                "using System.Net.Http;",
+                "using System.Text;",
+                "using System.Threading;",
+                "using System.Threading.Tasks;",
+            ]),
+            CompatibilityMode::Net40Hwr => imports.extend(["using System.Net;", "using System.IO;", "using System.Text;"]),
+        }
+
+        imports.join("\n") + "\n"
+    }
+
+    /// Generate the namespace opening line, e.g. `namespace Helloworld\n{`.
+    fn generate_namespace_open(&self, proto: &ProtoFile) -> String {
+        let default_ns = proto.default_namespace_for(&CSharp);
+        let ns = self.namespace.as_ref().unwrap_or(&default_ns);
+        format!("namespace {}\n{{", ns)
+    }
+
+    /// Generate enum definitions, sorted by name for deterministic output
+    /// (proto enums are stored in a `HashMap`, so iteration order alone
+    /// isn't stable across runs).
+    fn generate_enums(&self, proto: &ProtoFile) -> String {
+        let mut enums: Vec<_> = proto.enums().values().collect();
+        enums.sort_by_key(|proto_enum| proto_enum.name().as_str());
+
+        enums
+            .into_iter()
+            .map(|proto_enum| self.generate_enum(proto_enum))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn generate_enum(&self, proto_enum: &ProtoEnum) -> String {
+        let enum_name = proto_enum.name();
+        let mut values: Vec<_> = proto_enum.values().iter().collect();
+        values.sort_by_key(|(_, value)| **value);
+
+        let values = values
+            .into_iter()
+            .map(|(key, value)| format!("        {} = {},", key, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("    public enum {}\n    {{\n{}\n    }}", enum_name, values)
+    }
+
+    /// Generate message definitions (DTOs)
+    fn generate_messages(&self, proto: &ProtoFile) -> String {
+        let mut messages: Vec<_> = proto.messages().values().collect();
+        messages.sort_by_key(|message| message.name().as_str());
+
+        messages
+            .into_iter()
+            .map(|message| self.generate_message(message, proto, 1))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Generate a single message with nested messages. C# has no notion of a
+    /// class nested inside another class's body at the top level the way
+    /// VB.NET's generator renders them, so nested messages are rendered as
+    /// real nested classes, indented one level further.
+    fn generate_message(&self, message: &ProtoMessage, proto: &ProtoFile, indent_level: usize) -> String {
+        let indent = "    ".repeat(indent_level);
+        let mut lines = Vec::new();
+
+        lines.push(format!("{}public class {}", indent, message.name()));
+        lines.push(format!("{}{{", indent));
+
+        for field in message.fields() {
+            let prop_type = field.field_type().to_target_type(&CSharp, proto.package());
+            let prop_name = to_pascal_case(field.name().as_str());
+            let json_name = to_camel_case(field.name().as_str());
+
+            lines.push(format!("{}    [JsonProperty(\"{}\")]", indent, json_name));
+            lines.push(format!("{}    public {} {} {{ get; set; }}", indent, prop_type, prop_name));
+            lines.push("".to_string());
+        }
+
+        for oneof in message.oneofs() {
+            lines.push(self.generate_oneof(oneof, proto, indent_level));
+        }
+
+        let mut nested_messages: Vec<_> = message.nested_messages().values().collect();
+        nested_messages.sort_by_key(|nested| nested.name().as_str());
+        for nested in nested_messages {
+            lines.push(self.generate_message(nested, proto, indent_level + 1));
+        }
+
+        // Drop the trailing blank line left by the last field, if any, before closing the class.
+        if lines.last().map(String::is_empty).unwrap_or(false) {
+            lines.pop();
+        }
+
+        lines.push(format!("{}}}", indent));
+        lines.join("\n")
+    }
+
+    /// Generate a `oneof` group as a discriminator enum plus one nullable
+    /// property per variant, mirroring
+    /// [`crate::vb_codegen::VbNetGenerator::generate_oneof`].
+    fn generate_oneof(&self, oneof: &ProtoOneof, proto: &ProtoFile, indent_level: usize) -> String {
+        let indent = "    ".repeat(indent_level);
+        let enum_name = oneof.discriminator_enum_name();
+        let mut lines = Vec::new();
+
+        lines.push(format!("{}public enum {}", indent, enum_name));
+        lines.push(format!("{}{{", indent));
+        lines.push(format!("{}    None,", indent));
+        for variant in oneof.variants() {
+            lines.push(format!("{}    {},", indent, to_pascal_case(variant.name().as_str())));
+        }
+        lines.push(format!("{}}}", indent));
+        lines.push("".to_string());
+
+        lines.push(format!(
+            "{}public {} {}Case {{ get; set; }}",
+            indent,
+            enum_name,
+            to_pascal_case(oneof.name().as_str())
+        ));
+        lines.push("".to_string());
+
+        for variant in oneof.variants() {
+            let prop_type = variant.field_type().to_target_type(&CSharp, proto.package());
+            let prop_name = to_pascal_case(variant.name().as_str());
+            let json_name = to_camel_case(variant.name().as_str());
+
+            lines.push(format!("{}[JsonProperty(\"{}\")]", indent, json_name));
+            lines.push(format!("{}public {} {} {{ get; set; }}", indent, prop_type, prop_name));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Generate service client definitions
+    fn generate_services(&self, proto: &ProtoFile) -> String {
+        proto
+            .services()
+            .iter()
+            .map(|service| self.generate_service(service, proto))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn generate_service(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        match self.compat_mode {
+            CompatibilityMode::Net45 => self.generate_service_net45(service, proto),
+            CompatibilityMode::Net40Hwr => self.generate_service_net40hwr(service, proto),
+        }
+    }
+
+    /// Generate service client for .NET 4.5+ mode (`HttpClient` + async/await)
+    fn generate_service_net45(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        let client_name = format!("{}Client", service.name());
+        let mut lines = vec![
+            format!("    public class {}", client_name),
+            "    {".to_string(),
+            "        private readonly HttpClient _http;".to_string(),
+            "        private readonly string _baseUrl;".to_string(),
+            "".to_string(),
+            format!("        public {}(HttpClient http, string baseUrl)", client_name),
+            "        {".to_string(),
+            "            _http = http ?? throw new ArgumentNullException(nameof(http));".to_string(),
+            "            if (string.IsNullOrWhiteSpace(baseUrl)) throw new ArgumentException(\"baseUrl cannot be null or empty\");".to_string(),
+            "            _baseUrl = baseUrl.TrimEnd('/');".to_string(),
+            "        }".to_string(),
+            "".to_string(),
+            "        private async Task<TResp> PostJsonAsync<TReq, TResp>(string relativePath, TReq request, CancellationToken cancellationToken)".to_string(),
+            "        {".to_string(),
+            "            if (request == null) throw new ArgumentNullException(nameof(request));".to_string(),
+            "            var url = $\"{_baseUrl}/{relativePath.TrimStart('/')}\";".to_string(),
+            "            var json = JsonConvert.SerializeObject(request);".to_string(),
+            "            using (var content = new StringContent(json, Encoding.UTF8, \"application/json\"))".to_string(),
+            "            {".to_string(),
+            "                var response = await _http.PostAsync(url, content, cancellationToken).ConfigureAwait(false);".to_string(),
+            "                var body = await response.Content.ReadAsStringAsync().ConfigureAwait(false);".to_string(),
+            "                if (!response.IsSuccessStatusCode)".to_string(),
+            "                {".to_string(),
+            "                    throw new HttpRequestException($\"Request failed with status {(int)response.StatusCode} ({response.ReasonPhrase}): {body}\");".to_string(),
+            "                }".to_string(),
+            "                return JsonConvert.DeserializeObject<TResp>(body);".to_string(),
+            "            }".to_string(),
+            "        }".to_string(),
+            "".to_string(),
+        ];
+
+        for rpc in service.unary_rpcs() {
+            lines.extend(self.generate_rpc_method_net45(rpc, proto));
+            lines.push("".to_string());
+        }
+
+        lines.push("    }".to_string());
+        lines.join("\n")
+    }
+
+    fn generate_rpc_method_net45(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = format!("{}Async", rpc.name());
+        let input_type = rpc.input_type().to_target_type(&CSharp, proto.package());
+        let output_type = rpc.output_type().to_target_type(&CSharp, proto.package());
+        let relative_path = self.build_relative_path(rpc, proto);
+
+        vec![
+            format!(
+                "        public async Task<{}> {}({} request, CancellationToken cancellationToken = default(CancellationToken))",
+                output_type, method_name, input_type
+            ),
+            "        {".to_string(),
+            format!(
+                "            return await PostJsonAsync<{}, {}>(\"{}\", request, cancellationToken).ConfigureAwait(false);",
+                input_type, output_type, relative_path
+            ),
+            "        }".to_string(),
+        ]
+    }
+
+    /// Generate service client for .NET 4.0 `HttpWebRequest` mode (synchronous)
+    fn generate_service_net40hwr(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        let client_name = format!("{}Client", service.name());
+        let mut lines = vec![
+            format!("    public class {}", client_name),
+            "    {".to_string(),
+            "        private readonly string _baseUrl;".to_string(),
+            "".to_string(),
+            format!("        public {}(string baseUrl)", client_name),
+            "        {".to_string(),
+            "            if (string.IsNullOrWhiteSpace(baseUrl)) throw new ArgumentException(\"baseUrl cannot be null or empty\");".to_string(),
+            "            _baseUrl = baseUrl.TrimEnd('/');".to_string(),
+            "        }".to_string(),
+            "".to_string(),
+            "        private TResp PostJson<TReq, TResp>(string relativePath, TReq request)".to_string(),
+            "        {".to_string(),
+            "            if (request == null) throw new ArgumentNullException(\"request\");".to_string(),
+            "            var url = $\"{_baseUrl}/{relativePath.TrimStart('/')}\";".to_string(),
+            "            var bytes = Encoding.UTF8.GetBytes(JsonConvert.SerializeObject(request));".to_string(),
+            "".to_string(),
+            "            var webRequest = (HttpWebRequest)WebRequest.Create(url);".to_string(),
+            "            webRequest.Method = \"POST\";".to_string(),
+            "            webRequest.ContentType = \"application/json\";".to_string(),
+            "            webRequest.ContentLength = bytes.Length;".to_string(),
+            "            using (var stream = webRequest.GetRequestStream())".to_string(),
+            "            {".to_string(),
+            "                stream.Write(bytes, 0, bytes.Length);".to_string(),
+            "            }".to_string(),
+            "".to_string(),
+            "            try".to_string(),
+            "            {".to_string(),
+            "                using (var response = (HttpWebResponse)webRequest.GetResponse())".to_string(),
+            "                using (var reader = new StreamReader(response.GetResponseStream()))".to_string(),
+            "                {".to_string(),
+            "                    return JsonConvert.DeserializeObject<TResp>(reader.ReadToEnd());".to_string(),
+            "                }".to_string(),
+            "            }".to_string(),
+            "            catch (WebException ex)".to_string(),
+            "            {".to_string(),
+            "                var errorResp = ex.Response as HttpWebResponse;".to_string(),
+            "                if (errorResp == null) throw;".to_string(),
+            "                using (var reader = new StreamReader(errorResp.GetResponseStream()))".to_string(),
+            "                {".to_string(),
+            "                    var errorBody = reader.ReadToEnd();".to_string(),
+            "                    throw new WebException($\"Request failed with status {(int)errorResp.StatusCode} ({errorResp.StatusDescription}): {errorBody}\");".to_string(),
+            "                }".to_string(),
+            "            }".to_string(),
+            "        }".to_string(),
+            "".to_string(),
+        ];
+
+        for rpc in service.unary_rpcs() {
+            lines.extend(self.generate_rpc_method_net40hwr(rpc, proto));
+            lines.push("".to_string());
+        }
+
+        lines.push("    }".to_string());
+        lines.join("\n")
+    }
+
+    fn generate_rpc_method_net40hwr(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = rpc.name().to_string();
+        let input_type = rpc.input_type().to_target_type(&CSharp, proto.package());
+        let output_type = rpc.output_type().to_target_type(&CSharp, proto.package());
+        let relative_path = self.build_relative_path(rpc, proto);
+
+        vec![
+            format!("        public {} {}({} request)", output_type, method_name, input_type),
+            "        {".to_string(),
+            format!(
+                "            return PostJson<{}, {}>(\"{}\", request);",
+                input_type, output_type, relative_path
+            ),
+            "        }".to_string(),
+        ]
+    }
+
+    /// Build the relative URL path for an RPC method, matching
+    /// [`crate::rust_codegen::RustGenerator::build_relative_path`]'s
+    /// `/{file_stem}/{kebab-rpc}/v{version}` convention (the same one
+    /// [`crate::vb_codegen::VbNetGenerator::build_relative_path`] uses for
+    /// [`WireProtocol::Legacy`]) so every backend routes to the same endpoints.
+    fn build_relative_path(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
+        let file_stem = Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        format!("/{}/{}/v{}", file_stem, rpc.url_name(), rpc.version())
+    }
+}
+
+impl CodeGenerator for CSharpGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let code = self.generate_code(proto)?;
+
+        fs::create_dir_all(output_dir)?;
+
+        let file_name = Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let output_file = output_dir.join(format!("{}.cs", file_name));
+
+        fs::write(&output_file, code)?;
+        Ok(output_file)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        let mut sections = Vec::new();
+
+        sections.push(self.generate_imports());
+        sections.push(self.generate_namespace_open(proto));
+        sections.push("".to_string());
+
+        let enums = self.generate_enums(proto);
+        if !enums.is_empty() {
+            sections.push(enums);
+            sections.push("".to_string());
+        }
+
+        let messages = self.generate_messages(proto);
+        if !messages.is_empty() {
+            sections.push(messages);
+            sections.push("".to_string());
+        }
+
+        let services = self.generate_services(proto);
+        if !services.is_empty() {
+            sections.push(services);
+            sections.push("".to_string());
+        }
+
+        sections.push("}".to_string());
+
+        Ok(sections.join("\n"))
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "cs"
+    }
+
+    fn description(&self) -> &'static str {
+        "C# HTTP proxy client and DTO generator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_proto;
+
+    #[test]
+    fn test_csharp_code_generation_net45() {
+        let proto = create_test_proto("name");
+        let generator = CSharpGenerator::new(None, CompatibilityMode::Net45);
+
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("namespace Helloworld"));
+        assert!(code.contains("public class HelloRequest"));
+        assert!(code.contains("[JsonProperty(\"name\")]"));
+        assert!(code.contains("public string Name { get; set; }"));
+        assert!(code.contains("public class GreeterClient"));
+        assert!(code.contains("public GreeterClient(HttpClient http, string baseUrl)"));
+        assert!(code.contains("public async Task<HelloReply> SayHelloAsync(HelloRequest request, CancellationToken cancellationToken = default(CancellationToken))"));
+        assert!(code.contains("/helloworld/say-hello/v1"));
+        assert!(code.contains("using Newtonsoft.Json;"));
+    }
+
+    #[test]
+    fn test_csharp_code_generation_net40hwr() {
+        let proto = create_test_proto("name");
+        let generator = CSharpGenerator::new(None, CompatibilityMode::Net40Hwr);
+
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("public class GreeterClient"));
+        assert!(code.contains("public GreeterClient(string baseUrl)"));
+        assert!(code.contains("public HelloReply SayHello(HelloRequest request)"));
+        assert!(code.contains("(HttpWebRequest)WebRequest.Create(url)"));
+        assert!(!code.contains("HttpClient"));
+        assert!(!code.contains("async"));
+    }
+
+    #[test]
+    fn test_custom_namespace() {
+        let proto = create_test_proto("name");
+        let generator = CSharpGenerator::new(Some("Custom.Namespace".to_string()), CompatibilityMode::Net45);
+
+        let code = generator.generate_code(&proto).unwrap();
+        assert!(code.contains("namespace Custom.Namespace"));
+    }
+
+    #[test]
+    fn test_oneof_generates_discriminator_and_nullable_properties() {
+        let oneof = ProtoOneofBuilder::default()
+            .name(Identifier::new("payload").unwrap())
+            .variants(vec![
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("text").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(1)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("number").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::Int32))
+                    .field_number(2)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let message = ProtoMessageBuilder::default()
+            .name(Identifier::new("Event").unwrap())
+            .oneofs(vec![oneof])
+            .build()
+            .unwrap();
+
+        let proto = ProtoFileBuilder::default().file_name("event.proto".to_string()).build().unwrap();
+
+        let generator = CSharpGenerator::new(None, CompatibilityMode::Net45);
+        let code = generator.generate_message(&message, &proto, 1);
+
+        assert!(code.contains("public enum PayloadCase"));
+        assert!(code.contains("Text,"));
+        assert!(code.contains("Number,"));
+        assert!(code.contains("public PayloadCase PayloadCase { get; set; }"));
+        assert!(code.contains("[JsonProperty(\"text\")]"));
+        assert!(code.contains("public string Text { get; set; }"));
+    }
+
+    #[test]
+    fn test_rpc_url_naming_matches_rust_and_vbnet_legacy_conventions() {
+        let proto = create_test_proto("name");
+        let generator = CSharpGenerator::new(None, CompatibilityMode::Net45);
+        let rpc = &proto.services()[0].rpcs()[0];
+        assert_eq!(generator.build_relative_path(rpc, &proto), "/helloworld/say-hello/v1");
+    }
+}
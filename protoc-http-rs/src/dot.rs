@@ -0,0 +1,351 @@
+use crate::codegen::CodeGenerator;
+use crate::error::Result;
+use crate::parser::ProtoParser;
+use crate::types::{ProtoFile, ProtoType};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Graph flavor selecting the Graphviz keyword and edge operator to emit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Directed graph (`digraph`), edges rendered with `->`
+    Digraph,
+    /// Undirected graph (`graph`), edges rendered with `--`
+    Graph,
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::Digraph
+    }
+}
+
+impl Kind {
+    fn keyword(self) -> &'static str {
+        match self {
+            Kind::Digraph => "digraph",
+            Kind::Graph => "graph",
+        }
+    }
+
+    fn edge_op(self) -> &'static str {
+        match self {
+            Kind::Digraph => "->",
+            Kind::Graph => "--",
+        }
+    }
+}
+
+/// Builds a Graphviz DOT document describing a proto schema's message, enum,
+/// and service graph.
+struct DotBuilder {
+    kind: Kind,
+    nodes: Vec<String>,
+    edges: Vec<String>,
+}
+
+impl DotBuilder {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    fn add_node(&mut self, id: &str, label: &str, shape: &str) {
+        self.nodes.push(format!(
+            "  {} [label={}, shape={}];",
+            quote(id),
+            quote(label),
+            shape
+        ));
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str) {
+        self.edges
+            .push(format!("  {} {} {};", quote(from), self.kind.edge_op(), quote(to)));
+    }
+
+    fn build(self) -> String {
+        let mut lines = Vec::new();
+        lines.push(format!("{} proto_schema {{", self.kind.keyword()));
+        lines.extend(self.nodes);
+        lines.extend(self.edges);
+        lines.push("}".to_string());
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Escape/quote a node id so it survives dotted package names and other
+/// special characters.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Compute the node id referenced by a `ProtoType`, unwrapping `Repeated`
+/// and `Map` to their respective inner/value type. Returns `None` for
+/// scalar types, which have no node.
+fn referenced_node(proto_type: &ProtoType) -> Option<String> {
+    match proto_type {
+        ProtoType::Scalar(_) => None,
+        ProtoType::Message { name, package } | ProtoType::Enum { name, package } => {
+            Some(match package {
+                Some(pkg) => format!("{}.{}", pkg.as_str(), name),
+                None => name.clone(),
+            })
+        }
+        ProtoType::Repeated(inner) => referenced_node(inner),
+        ProtoType::Map { value, .. } => referenced_node(value),
+    }
+}
+
+/// Generate a Graphviz DOT document describing `file`'s schema structure:
+/// messages, enums, and services as nodes, with edges for field references
+/// and RPC input/output types.
+pub fn to_dot(file: &ProtoFile) -> String {
+    to_dot_with_kind(file, Kind::default())
+}
+
+/// Same as [`to_dot`] but with an explicit graph [`Kind`].
+pub fn to_dot_with_kind(file: &ProtoFile, kind: Kind) -> String {
+    let mut builder = DotBuilder::new(kind);
+
+    let mut messages: Vec<_> = file.messages().values().collect();
+    messages.sort_by_key(|message| message.name().as_str());
+    for message in messages {
+        let node_id = message.name().as_str().to_string();
+        builder.add_node(&node_id, message.name().as_str(), "box");
+
+        for field in message.fields() {
+            if let Some(target) = referenced_node(field.field_type()) {
+                builder.add_edge(&node_id, &target);
+            }
+        }
+    }
+
+    let mut enums: Vec<_> = file.enums().values().collect();
+    enums.sort_by_key(|proto_enum| proto_enum.name().as_str());
+    for proto_enum in enums {
+        builder.add_node(proto_enum.name().as_str(), proto_enum.name().as_str(), "ellipse");
+    }
+
+    for service in file.services() {
+        let service_node = service.name().as_str().to_string();
+        builder.add_node(&service_node, service.name().as_str(), "component");
+
+        for rpc in service.rpcs() {
+            let rpc_node = format!("{}.{}", service.name().as_str(), rpc.name().as_str());
+            builder.add_node(&rpc_node, rpc.name().as_str(), "diamond");
+            builder.add_edge(&service_node, &rpc_node);
+
+            if let Some(input) = referenced_node(rpc.input_type()) {
+                builder.add_edge(&rpc_node, &input);
+            }
+            if let Some(output) = referenced_node(rpc.output_type()) {
+                builder.add_edge(&rpc_node, &output);
+            }
+        }
+    }
+
+    builder.build()
+}
+
+/// Emits a Graphviz DOT document (`<name>.dot`) describing a proto
+/// schema's message/enum/service graph, for piping into `dot -Tsvg` or
+/// similar (`--emit dot`).
+pub struct DotGenerator;
+
+impl DotGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CodeGenerator for DotGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let dot_dir = output_dir.join("dot");
+        fs::create_dir_all(&dot_dir)?;
+
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let dot_string = self.generate_code(proto)?;
+
+        let output_path = dot_dir.join(format!("{}.dot", base_name));
+        fs::write(&output_path, dot_string)?;
+
+        Ok(output_path)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        Ok(to_dot(proto))
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "dot"
+    }
+
+    fn description(&self) -> &'static str {
+        "Graphviz DOT document of the message/enum/service graph (<name>.dot)"
+    }
+}
+
+/// Generate `.dot` documents for every file in `proto_files`, writing each
+/// into `output_dir/dot/`. Mirrors [`crate::ir_codegen::generate_ir_for_directory`].
+pub fn generate_dot_for_directory(
+    proto_files: &[PathBuf],
+    parser: &ProtoParser,
+    output_dir: &Path,
+) -> Vec<Result<PathBuf>> {
+    let generator = DotGenerator::new();
+
+    proto_files
+        .iter()
+        .map(|proto_file| {
+            let proto = parser.parse_file(proto_file)?;
+            generator.generate_to_file(&proto, output_dir)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        Identifier, PackageName, ProtoFieldBuilder, ProtoFileBuilder, ProtoMessageBuilder,
+        ProtoRpcBuilder, ProtoServiceBuilder, ScalarType,
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_to_dot_defaults_to_digraph() {
+        let file = ProtoFileBuilder::default()
+            .file_name("empty.proto".to_string())
+            .build()
+            .unwrap();
+
+        let dot = to_dot(&file);
+        assert!(dot.starts_with("digraph proto_schema {"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_to_dot_emits_message_field_edges() {
+        let request = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloRequest").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("name").unwrap())
+                .field_type(ProtoType::Scalar(ScalarType::String))
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let reply = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloReply").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("request").unwrap())
+                .field_type(ProtoType::Message {
+                    name: "HelloRequest".to_string(),
+                    package: None,
+                })
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let mut messages = HashMap::new();
+        messages.insert("HelloRequest".to_string(), request);
+        messages.insert("HelloReply".to_string(), reply);
+
+        let rpc = ProtoRpcBuilder::default()
+            .name(Identifier::new("SayHello").unwrap())
+            .input_type(ProtoType::Message {
+                name: "HelloRequest".to_string(),
+                package: None,
+            })
+            .output_type(ProtoType::Message {
+                name: "HelloReply".to_string(),
+                package: None,
+            })
+            .build()
+            .unwrap();
+
+        let service = ProtoServiceBuilder::default()
+            .name(Identifier::new("Greeter").unwrap())
+            .rpcs(vec![rpc])
+            .build()
+            .unwrap();
+
+        let file = ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .messages(messages)
+            .services(vec![service])
+            .build()
+            .unwrap();
+
+        let dot = to_dot(&file);
+        assert!(dot.contains("\"HelloReply\" -> \"HelloRequest\""));
+        assert!(dot.contains("\"Greeter\" -> \"Greeter.SayHello\""));
+        assert!(dot.contains("\"Greeter.SayHello\" -> \"HelloRequest\""));
+        assert!(dot.contains("\"Greeter.SayHello\" -> \"HelloReply\""));
+    }
+
+    #[test]
+    fn test_to_dot_emits_map_value_edge() {
+        let entry = ProtoMessageBuilder::default()
+            .name(Identifier::new("Entry").unwrap())
+            .build()
+            .unwrap();
+
+        let holder = ProtoMessageBuilder::default()
+            .name(Identifier::new("Holder").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("entries").unwrap())
+                .field_type(
+                    ProtoType::map(
+                        ScalarType::String,
+                        ProtoType::Message {
+                            name: "Entry".to_string(),
+                            package: None,
+                        },
+                    )
+                    .unwrap(),
+                )
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let mut messages = HashMap::new();
+        messages.insert("Entry".to_string(), entry);
+        messages.insert("Holder".to_string(), holder);
+
+        let file = ProtoFileBuilder::default()
+            .file_name("holder.proto".to_string())
+            .messages(messages)
+            .build()
+            .unwrap();
+
+        let dot = to_dot(&file);
+        assert!(dot.contains("\"Holder\" -> \"Entry\""));
+    }
+
+    #[test]
+    fn test_graph_kind_uses_undirected_operator() {
+        let file = ProtoFileBuilder::default()
+            .file_name("empty.proto".to_string())
+            .build()
+            .unwrap();
+
+        let dot = to_dot_with_kind(&file, Kind::Graph);
+        assert!(dot.starts_with("graph proto_schema {"));
+    }
+}
@@ -28,6 +28,19 @@ pub enum Error {
 
     #[error("JSON error: {0}")]
     Json(String),
+
+    #[error("Cannot resolve import \"{import}\" (from {importer:?}): searched {searched_dirs}")]
+    UnresolvedImport {
+        importer: PathBuf,
+        import: String,
+        searched_dirs: String,
+    },
+
+    #[error("Cannot resolve type \"{name}\": no matching message or enum was found in the loaded proto files")]
+    UnresolvedType { name: String },
+
+    #[error("Ambiguous type \"{name}\": {count} matching messages/enums were found in the loaded proto files")]
+    AmbiguousType { name: String, count: usize },
 }
 
 /// Result type alias for protoc-http-rs
@@ -54,4 +67,28 @@ impl Error {
     pub fn codegen_error(message: impl Into<String>) -> Self {
         Self::CodeGen(message.into())
     }
+
+    pub fn unresolved_import(
+        importer: impl Into<PathBuf>,
+        import: impl Into<String>,
+        searched_dirs: &[PathBuf],
+    ) -> Self {
+        Self::UnresolvedImport {
+            importer: importer.into(),
+            import: import.into(),
+            searched_dirs: searched_dirs
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    pub fn unresolved_type(name: impl Into<String>) -> Self {
+        Self::UnresolvedType { name: name.into() }
+    }
+
+    pub fn ambiguous_type(name: impl Into<String>, count: usize) -> Self {
+        Self::AmbiguousType { name: name.into(), count }
+    }
 }
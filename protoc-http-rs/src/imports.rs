@@ -0,0 +1,129 @@
+use crate::error::{Error, Result};
+use crate::parser::ProtoParser;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Resolve every `import "...";` statement reachable from `entry_files`
+/// against a protoc-style list of `--proto-path`/`-I` include directories,
+/// parsing each file at most once and returning the transitive closure
+/// (entry files plus everything they import, directly or transitively) in
+/// dependency-before-dependent order. Errors precisely, naming the
+/// unresolved import and the directories searched, the first time an
+/// `import` can't be found on disk.
+pub fn resolve_closure(
+    entry_files: &[PathBuf],
+    search_dirs: &[PathBuf],
+    parser: &ProtoParser,
+) -> Result<Vec<PathBuf>> {
+    let mut visited = HashSet::new();
+    let mut closure = Vec::new();
+
+    for entry in entry_files {
+        resolve_one(entry, search_dirs, parser, &mut visited, &mut closure)?;
+    }
+
+    Ok(closure)
+}
+
+fn resolve_one(
+    file: &Path,
+    search_dirs: &[PathBuf],
+    parser: &ProtoParser,
+    visited: &mut HashSet<PathBuf>,
+    closure: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let canonical = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+    if !visited.insert(canonical) {
+        return Ok(());
+    }
+
+    let proto = parser.parse_file(file)?;
+    for import in proto.imports() {
+        let imported_file = resolve_import_path(file, import, search_dirs)?;
+        resolve_one(&imported_file, search_dirs, parser, visited, closure)?;
+    }
+
+    closure.push(file.to_path_buf());
+    Ok(())
+}
+
+/// Search each `-I`/`--proto-path` directory, in order, for `import_path`,
+/// matching protoc's resolution rule: an import is resolved relative to an
+/// include directory, never relative to the importing file. When no
+/// `--proto-path` was given, fall back to the importing file's own
+/// directory so single-tree projects keep working unchanged.
+fn resolve_import_path(importer: &Path, import_path: &str, search_dirs: &[PathBuf]) -> Result<PathBuf> {
+    if search_dirs.is_empty() {
+        let dir = importer.parent().unwrap_or_else(|| Path::new("."));
+        let candidate = dir.join(import_path);
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+        return Err(Error::unresolved_import(importer, import_path, &[dir.to_path_buf()]));
+    }
+
+    for dir in search_dirs {
+        let candidate = dir.join(import_path);
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+    }
+
+    Err(Error::unresolved_import(importer, import_path, search_dirs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_closure_finds_import_via_proto_path() {
+        let common_dir = TempDir::new().unwrap();
+        fs::write(
+            common_dir.path().join("ticker.proto"),
+            r#"syntax = "proto3"; package common; message Ticker { string symbol = 1; }"#,
+        )
+        .unwrap();
+
+        let main_dir = TempDir::new().unwrap();
+        let main_file = main_dir.path().join("quote.proto");
+        fs::write(
+            &main_file,
+            r#"syntax = "proto3"; package quote; import "ticker.proto"; message Quote { common.Ticker ticker = 1; }"#,
+        )
+        .unwrap();
+
+        let parser = ProtoParser::new();
+        let closure = resolve_closure(
+            &[main_file.clone()],
+            &[common_dir.path().to_path_buf()],
+            &parser,
+        )
+        .unwrap();
+
+        assert_eq!(closure.len(), 2);
+        assert_eq!(closure[0], common_dir.path().join("ticker.proto"));
+        assert_eq!(closure[1], main_file);
+    }
+
+    #[test]
+    fn test_resolve_closure_reports_missing_import() {
+        let main_dir = TempDir::new().unwrap();
+        let main_file = main_dir.path().join("quote.proto");
+        fs::write(
+            &main_file,
+            r#"syntax = "proto3"; import "missing/ticker.proto"; message Quote {}"#,
+        )
+        .unwrap();
+
+        let other_dir = TempDir::new().unwrap();
+        let parser = ProtoParser::new();
+        let err = resolve_closure(&[main_file], &[other_dir.path().to_path_buf()], &parser).unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("missing/ticker.proto"));
+        assert!(message.contains(&other_dir.path().display().to_string()));
+    }
+}
@@ -0,0 +1,335 @@
+use crate::codegen::CodeGenerator;
+use crate::error::Result;
+use crate::parser::ProtoParser;
+use crate::types::{
+    PackageName, ProtoEnum, ProtoField, ProtoFile, ProtoMessage, ProtoOneof, ProtoRpc, ProtoService, ProtoType,
+    ScalarType,
+};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever a field's meaning or shape changes in a way a consumer
+/// of the IR would need to branch on.
+const IR_FORMAT_VERSION: u32 = 1;
+
+/// Emits the fully parsed [`ProtoFile`] as a flat, tagged JSON
+/// intermediate representation (`<name>.ir.json`), so a caller can build
+/// their own generator in another language against this crate's parser
+/// output instead of re-parsing `.proto` themselves (`--emit ir`).
+pub struct IrGenerator;
+
+impl IrGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CodeGenerator for IrGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let ir_dir = output_dir.join("ir");
+        fs::create_dir_all(&ir_dir)?;
+
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let json_string = self.generate_code(proto)?;
+
+        let output_path = ir_dir.join(format!("{}.ir.json", base_name));
+        fs::write(&output_path, json_string)?;
+
+        Ok(output_path)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&file_to_ir(proto))?)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn description(&self) -> &'static str {
+        "Full parsed proto AST as stable, tagged JSON (<name>.ir.json) for building generators in other languages"
+    }
+}
+
+fn file_to_ir(proto: &ProtoFile) -> Value {
+    let mut messages = Vec::new();
+    for msg in proto.messages().values() {
+        flatten_message(msg, &[], &mut messages);
+    }
+    messages.sort_by(|a: &Value, b: &Value| a["qualified_name"].as_str().cmp(&b["qualified_name"].as_str()));
+
+    let mut enums: Vec<Value> = proto.enums().values().map(enum_to_ir).collect();
+    enums.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    json!({
+        "format_version": IR_FORMAT_VERSION,
+        "file_name": proto.file_name(),
+        "package": proto.package().map(|pkg| pkg.as_str().to_string()),
+        "imports": proto.imports(),
+        "messages": messages,
+        "enums": enums,
+        "services": proto.services().iter().map(service_to_ir).collect::<Vec<_>>(),
+    })
+}
+
+/// Nested messages are flattened into the same list as their enclosing
+/// file's top-level messages, distinguished by a dotted `qualified_name`,
+/// matching [`crate::template_codegen`]'s template context and
+/// [`crate::json_schema_codegen::JsonSchemaGenerator`]'s `$defs` naming.
+fn flatten_message(msg: &ProtoMessage, parent_path: &[String], out: &mut Vec<Value>) {
+    let mut path = parent_path.to_vec();
+    path.push(msg.name().as_str().to_string());
+
+    out.push(json!({
+        "name": msg.name().as_str(),
+        "qualified_name": path.join("."),
+        "docs": msg.docs(),
+        "fields": msg.fields().iter().map(field_to_ir).collect::<Vec<_>>(),
+        "oneofs": msg.oneofs().iter().map(oneof_to_ir).collect::<Vec<_>>(),
+    }));
+
+    for nested in msg.nested_messages().values() {
+        flatten_message(nested, &path, out);
+    }
+}
+
+fn field_to_ir(field: &ProtoField) -> Value {
+    json!({
+        "name": field.name().as_str(),
+        "field_number": field.field_number(),
+        "optional": field.is_optional(),
+        "docs": field.docs(),
+        "type": proto_type_to_ir(field.field_type()),
+    })
+}
+
+fn oneof_to_ir(oneof: &ProtoOneof) -> Value {
+    json!({
+        "name": oneof.name().as_str(),
+        "docs": oneof.docs(),
+        "variants": oneof.variants().iter().map(field_to_ir).collect::<Vec<_>>(),
+    })
+}
+
+fn enum_to_ir(proto_enum: &ProtoEnum) -> Value {
+    let mut values: Vec<(&String, &i32)> = proto_enum.values().iter().collect();
+    values.sort_by_key(|(_, value)| **value);
+
+    json!({
+        "name": proto_enum.name().as_str(),
+        "docs": proto_enum.docs(),
+        "values": values
+            .into_iter()
+            .map(|(name, value)| json!({
+                "name": name,
+                "value": value,
+                "docs": proto_enum.value_docs().get(name),
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn service_to_ir(service: &ProtoService) -> Value {
+    json!({
+        "name": service.name().as_str(),
+        "docs": service.docs(),
+        "rpcs": service.rpcs().iter().map(rpc_to_ir).collect::<Vec<_>>(),
+    })
+}
+
+fn rpc_to_ir(rpc: &ProtoRpc) -> Value {
+    json!({
+        "name": rpc.name().as_str(),
+        "docs": rpc.docs(),
+        "input_type": proto_type_to_ir(rpc.input_type()),
+        "output_type": proto_type_to_ir(rpc.output_type()),
+        "client_streaming": rpc.client_streaming(),
+        "server_streaming": rpc.server_streaming(),
+    })
+}
+
+/// Tagged JSON for a [`ProtoType`]: `{"kind": ...}` plus whatever payload
+/// that kind carries. A message/enum reference carries its bare `name`
+/// and `package` separately (rather than one pre-joined string) so a
+/// consumer can tell a qualified reference from an unqualified one
+/// without parsing a name back apart.
+fn proto_type_to_ir(field_type: &ProtoType) -> Value {
+    match field_type {
+        ProtoType::Scalar(scalar) => json!({
+            "kind": "scalar",
+            "scalar": scalar_proto_keyword(scalar),
+        }),
+        ProtoType::Message { name, package } => json!({
+            "kind": "message",
+            "name": name,
+            "package": package.as_ref().map(PackageName::as_str),
+        }),
+        ProtoType::Enum { name, package } => json!({
+            "kind": "enum",
+            "name": name,
+            "package": package.as_ref().map(PackageName::as_str),
+        }),
+        ProtoType::Repeated(inner) => json!({
+            "kind": "repeated",
+            "element": proto_type_to_ir(inner),
+        }),
+        ProtoType::Map { key, value } => json!({
+            "kind": "map",
+            "key": proto_type_to_ir(key),
+            "value": proto_type_to_ir(value),
+        }),
+    }
+}
+
+fn scalar_proto_keyword(scalar: &ScalarType) -> &'static str {
+    match scalar {
+        ScalarType::String => "string",
+        ScalarType::Int32 => "int32",
+        ScalarType::Int64 => "int64",
+        ScalarType::UInt32 => "uint32",
+        ScalarType::UInt64 => "uint64",
+        ScalarType::Sint32 => "sint32",
+        ScalarType::Sint64 => "sint64",
+        ScalarType::Fixed32 => "fixed32",
+        ScalarType::Fixed64 => "fixed64",
+        ScalarType::Sfixed32 => "sfixed32",
+        ScalarType::Sfixed64 => "sfixed64",
+        ScalarType::Bool => "bool",
+        ScalarType::Float => "float",
+        ScalarType::Double => "double",
+        ScalarType::Bytes => "bytes",
+    }
+}
+
+/// Generate `<name>.ir.json` for every proto file under a directory,
+/// mirroring [`crate::json_schema_codegen::generate_json_schemas_for_directory`]'s
+/// per-file fan-out and error handling.
+pub fn generate_ir_for_directory(
+    proto_files: &[PathBuf],
+    parser: &ProtoParser,
+    output_dir: &Path,
+) -> Vec<Result<PathBuf>> {
+    let generator = IrGenerator::new();
+
+    proto_files
+        .iter()
+        .map(|proto_file| {
+            let proto = parser.parse_file(proto_file)?;
+            generator.generate_to_file(&proto, output_dir)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        Identifier, ProtoEnumBuilder, ProtoFieldBuilder, ProtoFileBuilder, ProtoMessageBuilder, ProtoRpcBuilder,
+        ProtoServiceBuilder,
+    };
+    use std::collections::HashMap;
+
+    fn test_proto() -> ProtoFile {
+        let request = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloRequest").unwrap())
+            .fields(vec![
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("user_name").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(1)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("nickname").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(2)
+                    .optional(true)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let status = ProtoEnumBuilder::default()
+            .name(Identifier::new("Status").unwrap())
+            .values(HashMap::from([("OK".to_string(), 0), ("ERROR".to_string(), 1)]))
+            .build()
+            .unwrap();
+
+        let say_hello_rpc = ProtoRpcBuilder::default()
+            .name(Identifier::new("SayHello").unwrap())
+            .input_type(ProtoType::Message { name: "HelloRequest".to_string(), package: None })
+            .output_type(ProtoType::Message { name: "HelloReply".to_string(), package: None })
+            .build()
+            .unwrap();
+
+        let greeter_service =
+            ProtoServiceBuilder::default().name(Identifier::new("Greeter").unwrap()).rpcs(vec![say_hello_rpc]).build().unwrap();
+
+        let mut messages = HashMap::new();
+        messages.insert("HelloRequest".to_string(), request);
+
+        let mut enums = HashMap::new();
+        enums.insert("Status".to_string(), status);
+
+        ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .messages(messages)
+            .enums(enums)
+            .services(vec![greeter_service])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ir_document_shape() {
+        let proto = test_proto();
+        let generator = IrGenerator::new();
+        let code = generator.generate_code(&proto).unwrap();
+        let ir: Value = serde_json::from_str(&code).unwrap();
+
+        assert_eq!(ir["format_version"], IR_FORMAT_VERSION);
+        assert_eq!(ir["package"], "helloworld");
+
+        let messages = ir["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["qualified_name"], "HelloRequest");
+
+        let fields = messages[0]["fields"].as_array().unwrap();
+        assert_eq!(fields[0]["name"], "user_name");
+        assert_eq!(fields[0]["optional"], false);
+        assert_eq!(fields[0]["type"]["kind"], "scalar");
+        assert_eq!(fields[0]["type"]["scalar"], "string");
+        assert_eq!(fields[1]["name"], "nickname");
+        assert_eq!(fields[1]["optional"], true);
+
+        assert_eq!(ir["enums"][0]["name"], "Status");
+
+        let rpc = &ir["services"][0]["rpcs"][0];
+        assert_eq!(rpc["input_type"]["kind"], "message");
+        assert_eq!(rpc["input_type"]["name"], "HelloRequest");
+    }
+
+    #[test]
+    fn test_proto_type_to_ir_repeated_and_map() {
+        let repeated = ProtoType::Repeated(Box::new(ProtoType::Scalar(ScalarType::Int32)));
+        let ir = proto_type_to_ir(&repeated);
+        assert_eq!(ir["kind"], "repeated");
+        assert_eq!(ir["element"]["scalar"], "int32");
+
+        let map = ProtoType::Map {
+            key: Box::new(ProtoType::Scalar(ScalarType::String)),
+            value: Box::new(ProtoType::Scalar(ScalarType::Int64)),
+        };
+        let ir = proto_type_to_ir(&map);
+        assert_eq!(ir["kind"], "map");
+        assert_eq!(ir["key"]["scalar"], "string");
+        assert_eq!(ir["value"]["scalar"], "int64");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::codegen::CodeGenerator;
 use crate::error::Result;
 use crate::parser::ProtoParser;
 use crate::types::{PackageName, ProtoEnum, ProtoFile, ProtoMessage, ProtoType, ScalarType};
@@ -13,50 +14,6 @@ impl JsonSchemaGenerator {
         Self
     }
 
-    pub fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
-        let json_dir = output_dir.join("json");
-        fs::create_dir_all(&json_dir)?;
-
-        let base_name = Path::new(proto.file_name())
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown");
-
-        let mut description = format!(
-            "JSON Schema definitions for all messages and enums in {}",
-            proto.file_name()
-        );
-        if let Some(pkg) = proto.package() {
-            description.push_str(&format!(" (package: {})", pkg.as_str()));
-        }
-
-        let mut defs = HashMap::new();
-
-        for proto_enum in proto.enums().values() {
-            let enum_schema = self.build_enum_schema(proto_enum);
-            defs.insert(proto_enum.name().as_str().to_string(), enum_schema);
-        }
-
-        for msg in proto.messages().values() {
-            self.build_message_schema(msg, &[], &mut defs, proto.package())?;
-        }
-
-        let schema_doc = json!({
-            "$schema": "https://json-schema.org/draft/2020-12/schema",
-            "$id": format!("https://example.com/schemas/{}.json", base_name),
-            "title": format!("Schemas for {}", proto.file_name()),
-            "description": description,
-            "$defs": defs
-        });
-
-        let json_string = serde_json::to_string_pretty(&schema_doc)?;
-
-        let output_path = json_dir.join(format!("{}.json", base_name));
-        fs::write(&output_path, json_string)?;
-
-        Ok(output_path)
-    }
-
     fn scalar_type_to_json_schema(&self, scalar: &ScalarType) -> Value {
         match scalar {
             ScalarType::String => json!({"type": "string"}),
@@ -77,7 +34,38 @@ impl JsonSchemaGenerator {
         }
     }
 
-    fn qualify_json_schema_ref(
+    /// The bare JSON Schema `"type"` keyword for a field, without the
+    /// format/bounds/encoding keywords [`Self::scalar_type_to_json_schema`]
+    /// adds. Reused by [`crate::template_codegen`] to keep its simplified
+    /// per-field template context in sync with this generator's own type
+    /// mapping.
+    pub(crate) fn json_type_name(field_type: &ProtoType) -> &'static str {
+        match field_type {
+            ProtoType::Scalar(scalar) => match scalar {
+                ScalarType::String | ScalarType::Bytes => "string",
+                ScalarType::Bool => "boolean",
+                ScalarType::Float | ScalarType::Double => "number",
+                ScalarType::Int32
+                | ScalarType::Int64
+                | ScalarType::UInt32
+                | ScalarType::UInt64
+                | ScalarType::Sint32
+                | ScalarType::Sint64
+                | ScalarType::Fixed32
+                | ScalarType::Fixed64
+                | ScalarType::Sfixed32
+                | ScalarType::Sfixed64 => "integer",
+            },
+            ProtoType::Repeated(_) => "array",
+            ProtoType::Map { .. } => "object",
+            ProtoType::Message { .. } | ProtoType::Enum { .. } => "object",
+        }
+    }
+
+    /// Shared with [`crate::openapi_codegen::OpenApiGenerator`], which
+    /// rewrites the `#/$defs/` prefix this produces to
+    /// `#/components/schemas/` for its OpenAPI 3.1 output.
+    pub(crate) fn qualify_json_schema_ref(
         &self,
         proto_type: &ProtoType,
         current_pkg: Option<&PackageName>,
@@ -104,6 +92,9 @@ impl JsonSchemaGenerator {
             ProtoType::Repeated(_) => {
                 panic!("qualify_json_schema_ref called on repeated type")
             }
+            ProtoType::Map { .. } => {
+                panic!("qualify_json_schema_ref called on map type")
+            }
         }
     }
 
@@ -121,6 +112,13 @@ impl JsonSchemaGenerator {
                     "items": items_schema
                 })
             }
+            ProtoType::Map { value, .. } => {
+                let value_schema = self.get_json_schema_type(value, current_pkg);
+                json!({
+                    "type": "object",
+                    "additionalProperties": value_schema
+                })
+            }
             ProtoType::Message { .. } | ProtoType::Enum { .. } => {
                 json!({
                     "$ref": self.qualify_json_schema_ref(field_type, current_pkg)
@@ -129,7 +127,10 @@ impl JsonSchemaGenerator {
         }
     }
 
-    fn build_enum_schema(&self, proto_enum: &ProtoEnum) -> Value {
+    /// Shared with [`crate::openapi_codegen::OpenApiGenerator`], whose
+    /// `components/schemas` entries are identical to this generator's
+    /// `$defs` entries (OpenAPI 3.1 schemas are JSON Schema 2020-12).
+    pub(crate) fn build_enum_schema(&self, proto_enum: &ProtoEnum) -> Value {
         let mut enum_values: Vec<String> = proto_enum
             .values()
             .keys()
@@ -152,7 +153,9 @@ impl JsonSchemaGenerator {
         })
     }
 
-    fn build_message_schema(
+    /// Shared with [`crate::openapi_codegen::OpenApiGenerator`]; see
+    /// [`Self::build_enum_schema`].
+    pub(crate) fn build_message_schema(
         &self,
         msg: &ProtoMessage,
         parent_path: &[String],
@@ -164,15 +167,40 @@ impl JsonSchemaGenerator {
         let qualified_name = current_path.join(".");
 
         let mut properties = Map::new();
+        let mut required = Vec::new();
         for field in msg.fields() {
             let field_name = to_camel_case(field.name().as_str());
-            let field_schema = self.get_json_schema_type(field.field_type(), current_pkg);
+            let base_schema = self.get_json_schema_type(field.field_type(), current_pkg);
+
+            // A `repeated`/`map` field defaults to empty rather than being
+            // absent, so it's never `required` and never wrapped nullable.
+            // A singular message field always has explicit presence in
+            // proto3 (there's no non-null default to fall back to), and an
+            // `optional` scalar/enum field opts into the same tracking -
+            // both are nullable instead of required. Everything else (a
+            // non-`optional` scalar/enum) has an implicit default value, so
+            // it's always present and goes in `required`.
+            let is_collection = matches!(field.field_type(), ProtoType::Repeated(_) | ProtoType::Map { .. });
+            let is_message = matches!(field.field_type(), ProtoType::Message { .. });
+            let nullable = !is_collection && (field.is_optional() || is_message);
+
+            let field_schema = if nullable {
+                json!({"anyOf": [base_schema, {"type": "null"}]})
+            } else {
+                base_schema
+            };
+
+            if !is_collection && !nullable {
+                required.push(field_name.clone());
+            }
+
             properties.insert(field_name, field_schema);
         }
 
         let schema = json!({
             "type": "object",
             "properties": properties,
+            "required": required,
             "additionalProperties": false
         });
 
@@ -186,6 +214,69 @@ impl JsonSchemaGenerator {
     }
 }
 
+impl CodeGenerator for JsonSchemaGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let json_dir = output_dir.join("json");
+        fs::create_dir_all(&json_dir)?;
+
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let json_string = self.generate_code(proto)?;
+
+        let output_path = json_dir.join(format!("{}.json", base_name));
+        fs::write(&output_path, json_string)?;
+
+        Ok(output_path)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let mut description = format!(
+            "JSON Schema definitions for all messages and enums in {}",
+            proto.file_name()
+        );
+        if let Some(pkg) = proto.package() {
+            description.push_str(&format!(" (package: {})", pkg.as_str()));
+        }
+
+        let mut defs = HashMap::new();
+
+        for proto_enum in proto.enums().values() {
+            let enum_schema = self.build_enum_schema(proto_enum);
+            defs.insert(proto_enum.name().as_str().to_string(), enum_schema);
+        }
+
+        for msg in proto.messages().values() {
+            self.build_message_schema(msg, &[], &mut defs, proto.package())?;
+        }
+
+        let schema_doc = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$id": format!("https://example.com/schemas/{}.json", base_name),
+            "title": format!("Schemas for {}", proto.file_name()),
+            "description": description,
+            "$defs": defs
+        });
+
+        Ok(serde_json::to_string_pretty(&schema_doc)?)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn description(&self) -> &'static str {
+        "JSON Schema (2020-12) definitions for every message and enum in a proto file"
+    }
+}
+
 pub fn generate_json_schemas_for_directory(
     proto_files: &[PathBuf],
     parser: &ProtoParser,
@@ -307,6 +398,17 @@ mod tests {
         assert_eq!(schema["items"]["type"], "string");
     }
 
+    #[test]
+    fn test_map_field_handling() {
+        let generator = JsonSchemaGenerator::new();
+
+        let map_type = ProtoType::map(ScalarType::String, ProtoType::Scalar(ScalarType::Int32)).unwrap();
+
+        let schema = generator.get_json_schema_type(&map_type, None);
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["additionalProperties"]["type"], "integer");
+    }
+
     #[test]
     fn test_to_camel_case() {
         assert_eq!(to_camel_case("hello_world"), "helloWorld");
@@ -359,7 +461,52 @@ mod tests {
         assert!(schemas.contains_key("Outer.Inner"));
 
         let outer_schema = &schemas["Outer"];
+        // A singular message field always has explicit presence in proto3,
+        // so it's wrapped nullable rather than listed as required.
         let inner_field_schema = &outer_schema["properties"]["innerField"];
-        assert_eq!(inner_field_schema["$ref"], "#/$defs/Outer.Inner");
+        assert_eq!(inner_field_schema["anyOf"][0]["$ref"], "#/$defs/Outer.Inner");
+        assert_eq!(inner_field_schema["anyOf"][1]["type"], "null");
+        assert!(outer_schema["required"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_field_presence_required_and_nullable() {
+        let msg = ProtoMessageBuilder::default()
+            .name(Identifier::new("Profile").unwrap())
+            .fields(vec![
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("user_id").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(1)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("nickname").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(2)
+                    .optional(true)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("tags").unwrap())
+                    .field_type(ProtoType::Repeated(Box::new(ProtoType::Scalar(ScalarType::String))))
+                    .field_number(3)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let generator = JsonSchemaGenerator::new();
+        let mut schemas = HashMap::new();
+        generator.build_message_schema(&msg, &[], &mut schemas, None).unwrap();
+
+        let schema = &schemas["Profile"];
+        let required: Vec<&str> = schema["required"].as_array().unwrap().iter().map(|v| v.as_str().unwrap()).collect();
+        assert_eq!(required, vec!["userId"]);
+
+        assert_eq!(schema["properties"]["userId"]["type"], "string");
+        assert_eq!(schema["properties"]["nickname"]["anyOf"][1]["type"], "null");
+        assert_eq!(schema["properties"]["tags"]["type"], "array");
     }
 }
@@ -1,24 +1,65 @@
 #![allow(clippy::all, dead_code)] // Suppress clippy warnings during refactoring
 
 use clap::Parser;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+mod check;
 mod codegen;
+mod csharp_codegen;
+mod dot;
 mod error;
+mod imports;
+mod ir_codegen;
 mod json_schema_codegen;
+mod openapi_codegen;
 mod parser;
+mod registry;
+mod report;
+mod rust_codegen;
+mod scaffold;
+mod target;
+mod template_codegen;
+#[cfg(test)]
+mod test_support;
 mod types;
 mod utils;
 mod vb_codegen;
+mod watch;
 
+use check::PlannedFile;
 use codegen::CodeGenerator;
+use csharp_codegen::CSharpGenerator;
 use error::Result;
 use parser::ProtoParser;
-use types::{CompatibilityMode, ProtoFile};
+use rayon::prelude::*;
+use rust_codegen::RustGenerator;
+use target::{CSharp, Rust as RustTarget, VbNet};
+use template_codegen::TemplateGenerator;
+use types::{
+    CompatibilityMode, CredentialMode, GenerationTarget, NamingConfig, ProtoFile, SerializationFormat, WireProtocol,
+};
 use vb_codegen::VbNetGenerator;
 use std::fs;
 use std::collections::HashMap;
 
+/// Code-generation backend selected via `--target`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum Target {
+    Vbnet,
+    Rust,
+    Csharp,
+}
+
+/// Output format for the generation run's console report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum Format {
+    /// Human-readable text (default)
+    Text,
+    /// Newline-delimited JSON: one `plan` event, then one `result` event
+    /// per generated file
+    Json,
+}
+
 #[derive(Parser)]
 #[command(name = "protoc-http-rs")]
 #[command(about = "Generate VB.NET Http proxy client and DTOs from .proto files (unary RPCs only)")]
@@ -46,15 +87,244 @@ struct Cli {
     /// Alias of --net40hwr for backward compatibility
     #[arg(long)]
     net40: bool,
+
+    /// Code-generation backend: VB.NET (default) or Rust (serde + reqwest)
+    #[arg(long, value_enum, default_value = "vbnet")]
+    target: Target,
+
+    /// Don't write generated files; compare them against what's already on
+    /// disk and exit non-zero if anything is stale, missing, or extra
+    #[arg(long)]
+    check: bool,
+
+    /// Console report format: human-readable text, or newline-delimited
+    /// JSON for build tooling to consume
+    #[arg(long, value_enum, default_value = "text")]
+    format: Format,
+
+    /// After the initial generation, keep running and regenerate whenever a
+    /// .proto under --proto changes (directory mode only)
+    #[arg(long)]
+    watch: bool,
+
+    /// Cap the number of threads used for parallel codegen (default: rayon's
+    /// own heuristic, roughly one per CPU core)
+    #[arg(long)]
+    jobs: Option<usize>,
+
+    /// Emit client methods for server-streaming RPCs (VB.NET target only).
+    /// Client- and bidirectional-streaming RPCs are still never emitted; a
+    /// diagnostic is printed to stderr for each one skipped.
+    #[arg(long)]
+    streaming: bool,
+
+    /// Wire protocol the generated VB.NET client speaks: this generator's
+    /// own ad-hoc JSON layout (default), Twirp routing and error envelopes,
+    /// or Connect's unary protocol and error envelopes (VB.NET target only).
+    #[arg(long, default_value = "legacy")]
+    wire_protocol: WireProtocol,
+
+    /// Gzip request bodies and accept gzip/deflate-compressed responses in
+    /// generated clients (VB.NET target only).
+    #[arg(long)]
+    enable_compression: bool,
+
+    /// Request/response payload encoding for generated clients: JSON via
+    /// Newtonsoft.Json (default), or binary protobuf via protobuf-net
+    /// (`application/protobuf`, `<ProtoMember(N)>`-annotated DTOs) for
+    /// interop with protobuf-encoded gRPC-gateway/Twirp endpoints (VB.NET
+    /// target only).
+    #[arg(long, default_value = "json")]
+    serialization_format: SerializationFormat,
+
+    /// Retry transient failures in generated clients: connection errors and
+    /// HTTP 502/503/504/429 (honoring `Retry-After` when present), with
+    /// exponential backoff and jitter, up to a constructor-tunable max
+    /// attempts. Non-retryable 4xx responses still fail fast (VB.NET target
+    /// only).
+    #[arg(long)]
+    enable_retry: bool,
+
+    /// Which side(s) of the HTTP/JSON gateway to emit: the `FooClient` HTTP
+    /// client (default), the `FooControllerBase` abstract ASP.NET server
+    /// stub, or both (VB.NET target only).
+    #[arg(long, default_value = "client")]
+    generation_target: GenerationTarget,
+
+    /// How the generated `FooClient` authenticates each outgoing call: no
+    /// authentication (default), a per-call bearer token awaited from a
+    /// `tokenProvider` delegate, or a static API-key header set once at
+    /// construction (VB.NET target only).
+    #[arg(long, default_value = "none")]
+    credential_mode: CredentialMode,
+
+    /// Path to a JSON file with generated-name overrides: `type_overrides`
+    /// (proto message/service/RPC name -> generated name), `property_casing`
+    /// (`camel-case`/`pascal-case`/`snake-case`/`as-is` for emitted
+    /// `<JsonProperty("...")>` values), and `namespace_overrides` (proto
+    /// package -> namespace). Every key is optional. Lets teams integrating
+    /// generated code match in-house naming conventions instead of
+    /// accepting the defaults (VB.NET target only).
+    #[arg(long)]
+    naming_config: Option<PathBuf>,
+
+    /// Minimum serialized request-body size, in bytes, before
+    /// --enable-compression gzips it; bodies at or under the threshold are
+    /// sent uncompressed. Defaults to 0, meaning every request body is
+    /// compressed whenever --enable-compression is set. Has no effect
+    /// without --enable-compression, and only gates unary request bodies;
+    /// streaming request bodies always compress when enabled (VB.NET target
+    /// only).
+    #[arg(long, default_value_t = 0)]
+    compression_threshold_bytes: usize,
+
+    /// Which output formats to generate: the `--target` backend (`vbnet`,
+    /// `rust`, or `csharp`, whichever is active), `json` for the per-file
+    /// JSON Schema side output, `openapi` for an OpenAPI 3.1 document
+    /// describing the same unary HTTP/JSON surface, `ir` for the full
+    /// parsed proto AST as stable JSON, `dot` for a Graphviz DOT document
+    /// of the message/enum/service graph, and/or `template` to render
+    /// `--template-dir` (requires that flag). Comma-separated; defaults to
+    /// the `--target` backend plus `json` and `openapi` (`ir`, `dot`, and
+    /// `template` are opt-in). See `--list-generators` for each one's
+    /// description and file extension.
+    #[arg(long, value_delimiter = ',', default_value = "vbnet,rust,csharp,json,openapi")]
+    emit: Vec<String>,
+
+    /// Print every `--emit` generator's name, description, and file
+    /// extension, then exit without generating anything.
+    #[arg(long)]
+    list_generators: bool,
+
+    /// Directory of Tera templates to render with `--emit template`, for
+    /// targeting a language this crate has no built-in generator for
+    /// (TypeScript, Go, Python, ...). Must contain an entry template named
+    /// `template.<ext>.tera`; `<ext>` becomes the generated files'
+    /// extension. Has no effect unless `--emit` includes `template`.
+    #[arg(long)]
+    template_dir: Option<PathBuf>,
+
+    /// Additional directory to search when resolving `import "...";`
+    /// statements, matching protoc's `-I`/`--proto_path`. May be repeated;
+    /// directories are searched in the order given. Defaults to each
+    /// importing file's own directory when omitted.
+    #[arg(short = 'I', long = "proto-path")]
+    proto_path: Vec<PathBuf>,
+}
+
+/// Load `--naming-config`, if given: read the file and parse it per
+/// [`NamingConfig::from_json`]. Absent the flag, generation proceeds with
+/// [`NamingConfig::default`] (no overrides).
+fn load_naming_config(path: Option<&Path>) -> Result<NamingConfig> {
+    match path {
+        Some(path) => {
+            let content = fs::read_to_string(path)?;
+            let value: serde_json::Value = serde_json::from_str(&content)?;
+            NamingConfig::from_json(&value)
+        }
+        None => Ok(NamingConfig::default()),
+    }
 }
 
-/// Generate VB.NET files from multiple proto files with shared utilities when appropriate
-fn generate_directory_with_shared_utilities(
+/// Build the `--emit` registry: every [`CodeGenerator`] this crate can run,
+/// keyed by the name accepted on `--emit`. Exactly one of `vbnet`/`rust`/
+/// `csharp` is ever relevant per run (selected by `--target`); `json`,
+/// `openapi`, `ir`, and `dot` each layer a side output on top of whichever
+/// one that is, and `template` only appears when `template_dir` is given.
+#[allow(clippy::too_many_arguments)]
+fn generator_registry(
+    namespace: Option<String>,
+    compat_mode: CompatibilityMode,
+    streaming: bool,
+    wire_protocol: WireProtocol,
+    enable_compression: bool,
+    serialization_format: SerializationFormat,
+    enable_retry: bool,
+    generation_target: GenerationTarget,
+    credential_mode: CredentialMode,
+    naming: NamingConfig,
+    compression_threshold_bytes: usize,
+    template_dir: Option<&Path>,
+) -> Result<Vec<(&'static str, Box<dyn CodeGenerator>)>> {
+    let mut registry: Vec<(&'static str, Box<dyn CodeGenerator>)> = vec![
+        (
+            "vbnet",
+            Box::new(VbNetGenerator::with_compression_threshold(
+                namespace.clone(),
+                compat_mode,
+                streaming,
+                wire_protocol,
+                enable_compression,
+                serialization_format,
+                enable_retry,
+                generation_target,
+                credential_mode,
+                naming,
+                compression_threshold_bytes,
+            )) as Box<dyn CodeGenerator>,
+        ),
+        ("rust", Box::new(RustGenerator::new(namespace.clone())) as Box<dyn CodeGenerator>),
+        ("csharp", Box::new(CSharpGenerator::new(namespace, compat_mode)) as Box<dyn CodeGenerator>),
+        ("json", Box::new(json_schema_codegen::JsonSchemaGenerator::new()) as Box<dyn CodeGenerator>),
+        ("openapi", Box::new(openapi_codegen::OpenApiGenerator::new()) as Box<dyn CodeGenerator>),
+        ("ir", Box::new(ir_codegen::IrGenerator::new()) as Box<dyn CodeGenerator>),
+        ("dot", Box::new(dot::DotGenerator::new()) as Box<dyn CodeGenerator>),
+    ];
+
+    if let Some(template_dir) = template_dir {
+        registry.push(("template", Box::new(TemplateGenerator::new(template_dir)?) as Box<dyn CodeGenerator>));
+    }
+
+    Ok(registry)
+}
+
+/// `--list-generators`: print every `--emit` generator's name, description,
+/// and file extension. Uses default construction parameters throughout —
+/// none of them affect a generator's `description()`/`file_extension()`.
+/// `template_dir` is threaded through so `--list-generators --template-dir
+/// ./tpl` also reports what that directory would render.
+fn print_generator_list(template_dir: Option<&Path>) -> Result<()> {
+    println!("Available --emit generators:");
+    let registry = generator_registry(
+        None,
+        CompatibilityMode::default(),
+        false,
+        WireProtocol::default(),
+        false,
+        SerializationFormat::default(),
+        false,
+        GenerationTarget::default(),
+        CredentialMode::default(),
+        NamingConfig::default(),
+        0,
+        template_dir,
+    )?;
+    for (name, generator) in &registry {
+        println!("  {:<8} .{:<5} {}", name, generator.file_extension(), generator.description());
+    }
+    Ok(())
+}
+
+/// Plan (but don't write) the files that would be generated from multiple
+/// proto files, grouping same-directory files behind a shared VB.NET HTTP
+/// utility class when appropriate. Shared by the normal write path and
+/// `--check`, so both always agree on exactly what would be produced.
+#[allow(clippy::too_many_arguments)]
+fn plan_directory_with_shared_utilities(
     proto_files: Vec<PathBuf>,
     out_dir: &PathBuf,
     namespace: Option<String>,
     compat_mode: CompatibilityMode,
-) -> Result<Vec<PathBuf>> {
+    streaming: bool,
+    wire_protocol: WireProtocol,
+    enable_compression: bool,
+    serialization_format: SerializationFormat,
+    enable_retry: bool,
+    generation_target: GenerationTarget,
+    credential_mode: CredentialMode,
+    naming: NamingConfig,
+    compression_threshold_bytes: usize,
+) -> Result<Vec<PlannedFile>> {
     if proto_files.is_empty() {
         return Ok(Vec::new());
     }
@@ -68,7 +338,7 @@ fn generate_directory_with_shared_utilities(
         by_directory.entry(parent_dir).or_default().push(proto_path);
     }
 
-    let mut all_generated = Vec::new();
+    let mut planned = Vec::new();
 
     for (_dir_path, files) in by_directory {
         if files.len() > 1 {
@@ -92,62 +362,259 @@ fn generate_directory_with_shared_utilities(
 
             let utility_name = format!("{}HttpUtility", utility_namespace);
 
-            // Generate shared utility file
+            // Plan the shared utility file
             let utility_code = VbNetGenerator::generate_http_utility(
                 &utility_name,
                 &utility_namespace,
                 compat_mode,
             )?;
-
-            fs::create_dir_all(out_dir)?;
-            let utility_path = out_dir.join(format!("{}.vb", utility_name));
-            fs::write(&utility_path, utility_code)?;
-            all_generated.push(utility_path);
-
-            // Generate individual proto files using shared utility
-            let generator = VbNetGenerator::new(namespace.clone(), compat_mode);
-            for (proto_file, proto) in files.iter().zip(protos.iter()) {
-                let out_path = generate_with_shared_utility(&generator, proto, out_dir, &utility_name)?;
-                all_generated.push(out_path);
-            }
+            planned.push(PlannedFile {
+                path: out_dir.join(format!("{}.vb", utility_name)),
+                content: utility_code,
+            });
+
+            // Plan individual proto files using the shared utility. The
+            // utility name above is fixed before this point, so every
+            // worker renders against the same shared name regardless of
+            // scheduling; rayon's collect preserves `protos`' order.
+            let generator = VbNetGenerator::with_compression_threshold(
+                namespace.clone(),
+                compat_mode,
+                streaming,
+                wire_protocol,
+                enable_compression,
+                serialization_format,
+                enable_retry,
+                generation_target,
+                credential_mode,
+                naming.clone(),
+                compression_threshold_bytes,
+            );
+            let mut rendered: Vec<PlannedFile> = protos
+                .par_iter()
+                .map(|proto| plan_with_shared_utility(&generator, proto, out_dir, &utility_name))
+                .collect::<Result<Vec<_>>>()?;
+            planned.append(&mut rendered);
         } else {
             // Single file in directory: generate without shared utility
-            let generator = VbNetGenerator::new(namespace.clone(), compat_mode);
-            for proto_file in files {
-                let proto = parser.parse_file(&proto_file)?;
-                let out_path = generator.generate_to_file(&proto, out_dir)?;
-                all_generated.push(out_path);
-            }
+            let generator = VbNetGenerator::with_compression_threshold(
+                namespace.clone(),
+                compat_mode,
+                streaming,
+                wire_protocol,
+                enable_compression,
+                serialization_format,
+                enable_retry,
+                generation_target,
+                credential_mode,
+                naming.clone(),
+                compression_threshold_bytes,
+            );
+            let mut rendered: Vec<PlannedFile> = files
+                .par_iter()
+                .map(|proto_file| {
+                    let proto = parser.parse_file(proto_file)?;
+                    let path = out_dir.join(format!(
+                        "{}.vb",
+                        std::path::Path::new(proto.file_name()).file_stem().unwrap_or_default().to_string_lossy()
+                    ));
+                    let content = generator.generate_code(&proto)?;
+                    Ok(PlannedFile { path, content })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            planned.append(&mut rendered);
         }
     }
 
-    Ok(all_generated)
+    Ok(planned)
 }
 
-/// Generate a VB.NET file using a shared utility class
-fn generate_with_shared_utility(
+/// Plan a VB.NET file that uses a shared utility class.
+fn plan_with_shared_utility(
     generator: &VbNetGenerator,
     proto: &ProtoFile,
     out_dir: &PathBuf,
     shared_utility_name: &str,
-) -> Result<PathBuf> {
+) -> Result<PlannedFile> {
     let code = generator.generate_code_with_shared_utility(proto, Some(shared_utility_name))?;
 
-    fs::create_dir_all(out_dir)?;
-
     let file_name = std::path::Path::new(proto.file_name())
         .file_stem()
         .unwrap_or_default()
         .to_string_lossy();
-    let output_file = out_dir.join(format!("{}.vb", file_name));
 
-    fs::write(&output_file, code)?;
-    Ok(output_file)
+    Ok(PlannedFile {
+        path: out_dir.join(format!("{}.vb", file_name)),
+        content: code,
+    })
+}
+
+/// Plan the Rust files that would be generated from multiple proto files.
+/// Rust has no shared-utility concept: each file generates independently,
+/// so rendering fans out across the thread pool with no prior sequential
+/// pass needed; rayon's collect keeps the result in `proto_files`' order.
+fn plan_directory_rust(proto_files: &[PathBuf], out_dir: &Path, namespace: Option<String>) -> Result<Vec<PlannedFile>> {
+    let parser = ProtoParser::new();
+    let generator = RustGenerator::new(namespace);
+    proto_files
+        .par_iter()
+        .map(|proto_file| {
+            let proto = parser.parse_file(proto_file)?;
+            let file_name = std::path::Path::new(proto.file_name())
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let content = generator.generate_code(&proto)?;
+            Ok(PlannedFile { path: out_dir.join(format!("{}.rs", file_name)), content })
+        })
+        .collect()
+}
+
+/// Plan the C# files that would be generated from multiple proto files.
+/// Like Rust, C# has no shared-utility concept: each file generates
+/// independently, fanning out across the thread pool the same way
+/// [`plan_directory_rust`] does.
+fn plan_directory_csharp(
+    proto_files: &[PathBuf],
+    out_dir: &Path,
+    namespace: Option<String>,
+    compat_mode: CompatibilityMode,
+) -> Result<Vec<PlannedFile>> {
+    let parser = ProtoParser::new();
+    let generator = CSharpGenerator::new(namespace, compat_mode);
+    proto_files
+        .par_iter()
+        .map(|proto_file| {
+            let proto = parser.parse_file(proto_file)?;
+            let file_name = std::path::Path::new(proto.file_name())
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let content = generator.generate_code(&proto)?;
+            Ok(PlannedFile { path: out_dir.join(format!("{}.cs", file_name)), content })
+        })
+        .collect()
+}
+
+/// Compute the output path and rendered namespace a proto file would get
+/// under `target`, matching the naming the `plan_*` functions use.
+fn output_path_and_namespace(proto: &ProtoFile, out_dir: &Path, target: Target, namespace: Option<&str>) -> (String, String) {
+    let file_stem = std::path::Path::new(proto.file_name()).file_stem().unwrap_or_default().to_string_lossy();
+    let extension = match target {
+        Target::Vbnet => "vb",
+        Target::Rust => "rs",
+        Target::Csharp => "cs",
+    };
+    let rendered_namespace = match namespace {
+        Some(ns) => ns.to_string(),
+        None => match target {
+            Target::Vbnet => proto.default_namespace_for(&VbNet),
+            Target::Rust => proto.default_namespace_for(&RustTarget),
+            Target::Csharp => proto.default_namespace_for(&CSharp),
+        },
+    };
+    (out_dir.join(format!("{}.{}", file_stem, extension)).display().to_string(), rendered_namespace)
+}
+
+/// Stream the `--format json` report for a generation run: one `plan`
+/// event with totals across every discovered proto file, then one
+/// `result` event per file describing exactly what was emitted (and which
+/// streaming RPCs were skipped), so tooling doesn't have to scrape the
+/// generated text.
+fn emit_generation_report(protos: &[ProtoFile], out_dir: &Path, target: Target, namespace: Option<&str>) {
+    let total_messages: usize = protos.iter().map(|p| p.messages().len()).sum();
+    let total_services: usize = protos.iter().map(|p| p.services().len()).sum();
+    report::emit(&report::plan_event(protos.len(), total_messages, total_services));
+
+    for proto in protos {
+        let (output_path, rendered_namespace) = output_path_and_namespace(proto, out_dir, target, namespace);
+        report::emit(&report::result_event(proto, &output_path, &rendered_namespace));
+    }
+}
+
+/// Human-readable label for a `--target` backend, used in CLI output.
+fn target_label(target: Target) -> &'static str {
+    match target {
+        Target::Vbnet => "VB.NET",
+        Target::Rust => "Rust",
+        Target::Csharp => "C#",
+    }
+}
+
+/// Print the result of `--check`: which files are stale, missing, or extra,
+/// and the first mismatch's diff (if any).
+fn print_check_report(report: &check::CheckReport, first_diff: Option<&str>) {
+    if let Some(diff) = first_diff {
+        println!("{}\n", diff);
+    }
+
+    if !report.stale.is_empty() {
+        println!("Stale (generated output differs from disk):");
+        for path in &report.stale {
+            println!("  {}", path.display());
+        }
+    }
+    if !report.missing.is_empty() {
+        println!("Missing (not yet written to disk):");
+        for path in &report.missing {
+            println!("  {}", path.display());
+        }
+    }
+    if !report.extra.is_empty() {
+        println!("Extra (on disk, no longer generated):");
+        for path in &report.extra {
+            println!("  {}", path.display());
+        }
+    }
+    if report.is_clean() {
+        println!("Up to date.");
+    }
+}
+
+/// The `--emit` name of the generator selected by `--target`, i.e. the one
+/// `vbnet`/`rust`/`csharp` entry in [`generator_registry`] that's actually
+/// relevant to this run.
+fn target_emit_name(target: Target) -> &'static str {
+    match target {
+        Target::Vbnet => "vbnet",
+        Target::Rust => "rust",
+        Target::Csharp => "csharp",
+    }
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if cli.list_generators {
+        print_generator_list(cli.template_dir.as_deref())?;
+        return Ok(());
+    }
+
+    let naming = load_naming_config(cli.naming_config.as_deref())?;
+    let emit_target = cli.emit.iter().any(|name| name == target_emit_name(cli.target));
+    let emit_json = cli.emit.iter().any(|name| name == "json");
+    let emit_openapi = cli.emit.iter().any(|name| name == "openapi");
+    let emit_ir = cli.emit.iter().any(|name| name == "ir");
+    let emit_dot = cli.emit.iter().any(|name| name == "dot");
+    let emit_template = cli.emit.iter().any(|name| name == "template");
+    if emit_template && cli.template_dir.is_none() {
+        return Err(error::Error::validation_error(
+            "--emit template requires --template-dir",
+        ));
+    }
+    let template_generator = cli
+        .template_dir
+        .as_deref()
+        .map(TemplateGenerator::new)
+        .transpose()?;
+
+    if let Some(jobs) = cli.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()
+            .map_err(|e| error::Error::validation_error(format!("failed to size thread pool: {}", e)))?;
+    }
+
     // Determine compatibility mode
     let compat_mode = if cli.net40hwr || cli.net40 {
         CompatibilityMode::Net40Hwr
@@ -164,52 +631,330 @@ fn main() -> Result<()> {
             return Ok(());
         }
 
-        // Use new directory-based generation with shared utilities
-        let generated = generate_directory_with_shared_utilities(
-            proto_files.clone(),
-            &cli.out,
-            cli.namespace,
-            compat_mode,
-        )?;
-
-        println!("Generated VB.NET:");
-        for path in generated {
-            println!("  {}", path.display());
+        // Validate that every `import "...";` reachable from the discovered
+        // files resolves against --proto-path, and that every message/enum
+        // reference resolves against the loaded tree, before any codegen
+        // runs. Per-file codegen below still parses each file on its own
+        // (cross-file types aren't substituted into the generated code
+        // yet), so the registry is used here purely for validation.
+        registry::ProtoRegistry::load(&proto_files, &cli.proto_path, &ProtoParser::new())?.validate_references()?;
+
+        let planned = if emit_target {
+            match cli.target {
+                // VB.NET groups same-directory files behind a shared HTTP utility class.
+                Target::Vbnet => plan_directory_with_shared_utilities(
+                    proto_files.clone(),
+                    &cli.out,
+                    cli.namespace.clone(),
+                    compat_mode,
+                    cli.streaming,
+                    cli.wire_protocol,
+                    cli.enable_compression,
+                    cli.serialization_format,
+                    cli.enable_retry,
+                    cli.generation_target,
+                    cli.credential_mode,
+                    naming.clone(),
+                    cli.compression_threshold_bytes,
+                )?,
+                // Rust has no equivalent shared-utility concept: each file generates independently.
+                Target::Rust => plan_directory_rust(&proto_files, &cli.out, cli.namespace.clone())?,
+                // Neither does C#.
+                Target::Csharp => plan_directory_csharp(&proto_files, &cli.out, cli.namespace.clone(), compat_mode)?,
+            }
+        } else {
+            Vec::new()
+        };
+
+        if cli.check {
+            // --emit excludes the --target backend entirely: nothing of
+            // its kind is supposed to be on disk, so there's nothing to
+            // check. JSON schemas aren't part of --check's scope either
+            // way; only the primary codegen backend's output is compared.
+            if emit_target {
+                let extension = match cli.target {
+                    Target::Vbnet => "vb",
+                    Target::Rust => "rs",
+                    Target::Csharp => "cs",
+                };
+                let existing = check::scan_existing_outputs(&cli.out, extension);
+                let (check_report, first_diff) = check::run(&planned, &existing);
+                print_check_report(&check_report, first_diff.as_deref());
+                if !check_report.is_clean() {
+                    std::process::exit(1);
+                }
+            } else {
+                println!("Up to date.");
+            }
+            return Ok(());
         }
 
-        // Generate JSON schemas
-        let json_results = json_schema_codegen::generate_json_schemas_for_directory(
-            &proto_files,
-            &ProtoParser::new(),
-            &cli.out,
-        );
-
-        let mut json_generated = Vec::new();
-        for result in json_results {
-            match result {
-                Ok(path) => json_generated.push(path),
-                Err(e) => eprintln!("Warning: JSON schema generation failed: {}", e),
+        fs::create_dir_all(&cli.out)?;
+        if emit_target {
+            // Writes are independent per file, so they fan out across the
+            // same pool as rendering; the report below walks `planned`
+            // itself, so its ordering never depends on write-thread
+            // scheduling.
+            planned.par_iter().try_for_each(|file| -> Result<()> {
+                fs::write(&file.path, &file.content)?;
+                Ok(())
+            })?;
+
+            if cli.format == Format::Json {
+                let parser = ProtoParser::new();
+                let protos: Result<Vec<ProtoFile>> = proto_files.iter().map(|f| parser.parse_file(f)).collect();
+                emit_generation_report(&protos?, &cli.out, cli.target, cli.namespace.as_deref());
+            } else {
+                println!("Generated {}:", target_label(cli.target));
+                for file in &planned {
+                    println!("  {}", file.path.display());
+                }
             }
         }
 
+        // Generate JSON schemas, unless --emit excludes them.
+        let json_generated = if emit_json {
+            let json_results = json_schema_codegen::generate_json_schemas_for_directory(
+                &proto_files,
+                &ProtoParser::new(),
+                &cli.out,
+            );
+
+            let mut json_generated = Vec::new();
+            for result in json_results {
+                match result {
+                    Ok(path) => json_generated.push(path),
+                    Err(e) => eprintln!("Warning: JSON schema generation failed: {}", e),
+                }
+            }
+            json_generated
+        } else {
+            Vec::new()
+        };
+
         if !json_generated.is_empty() {
             println!("\nGenerated JSON Schemas:");
             for path in json_generated {
                 println!("  {}", path.display());
             }
         }
+
+        // Generate OpenAPI 3.1 documents, unless --emit excludes them.
+        let openapi_generated = if emit_openapi {
+            let openapi_results =
+                openapi_codegen::generate_openapi_specs_for_directory(&proto_files, &ProtoParser::new(), &cli.out);
+
+            let mut openapi_generated = Vec::new();
+            for result in openapi_results {
+                match result {
+                    Ok(path) => openapi_generated.push(path),
+                    Err(e) => eprintln!("Warning: OpenAPI generation failed: {}", e),
+                }
+            }
+            openapi_generated
+        } else {
+            Vec::new()
+        };
+
+        if !openapi_generated.is_empty() {
+            println!("\nGenerated OpenAPI documents:");
+            for path in openapi_generated {
+                println!("  {}", path.display());
+            }
+        }
+
+        // Generate the proto IR, unless --emit excludes it.
+        let ir_generated = if emit_ir {
+            let ir_results = ir_codegen::generate_ir_for_directory(&proto_files, &ProtoParser::new(), &cli.out);
+
+            let mut ir_generated = Vec::new();
+            for result in ir_results {
+                match result {
+                    Ok(path) => ir_generated.push(path),
+                    Err(e) => eprintln!("Warning: IR generation failed: {}", e),
+                }
+            }
+            ir_generated
+        } else {
+            Vec::new()
+        };
+
+        if !ir_generated.is_empty() {
+            println!("\nGenerated IR:");
+            for path in ir_generated {
+                println!("  {}", path.display());
+            }
+        }
+
+        // Generate Graphviz DOT documents, unless --emit excludes them.
+        let dot_generated = if emit_dot {
+            let dot_results = dot::generate_dot_for_directory(&proto_files, &ProtoParser::new(), &cli.out);
+
+            let mut dot_generated = Vec::new();
+            for result in dot_results {
+                match result {
+                    Ok(path) => dot_generated.push(path),
+                    Err(e) => eprintln!("Warning: DOT generation failed: {}", e),
+                }
+            }
+            dot_generated
+        } else {
+            Vec::new()
+        };
+
+        if !dot_generated.is_empty() {
+            println!("\nGenerated DOT graphs:");
+            for path in dot_generated {
+                println!("  {}", path.display());
+            }
+        }
+
+        // Render --template-dir, unless --emit excludes it.
+        if emit_template {
+            let generator = template_generator.as_ref().expect("validated above: --emit template requires --template-dir");
+            let parser = ProtoParser::new();
+            println!("\nGenerated from template:");
+            for proto_file in &proto_files {
+                let proto = parser.parse_file(proto_file)?;
+                let output_path = generator.generate_to_file(&proto, &cli.out)?;
+                println!("  {}", output_path.display());
+            }
+        }
+
+        if cli.watch {
+            // `watch::run`/`plan_all` only re-plan the `--target` backend
+            // (vbnet/rust/csharp) on each cycle; it doesn't know how to
+            // regenerate the json/openapi/ir/dot/template side outputs, so
+            // warn rather than silently leave them stale on proto changes.
+            if emit_json || emit_openapi || emit_ir || emit_dot || emit_template {
+                eprintln!(
+                    "Warning: --watch only regenerates the --target backend ({}); json/openapi/ir/dot/template \
+                     outputs from --emit are not kept in sync and should be regenerated manually.",
+                    target_emit_name(cli.target)
+                );
+            }
+
+            let initial_contents = planned.into_iter().map(|file| (file.path, file.content)).collect();
+            watch::run(
+                cli.proto,
+                cli.out,
+                cli.namespace,
+                compat_mode,
+                cli.target,
+                cli.streaming,
+                cli.wire_protocol,
+                cli.enable_compression,
+                cli.serialization_format,
+                cli.enable_retry,
+                cli.generation_target,
+                cli.credential_mode,
+                naming.clone(),
+                cli.compression_threshold_bytes,
+                initial_contents,
+            )?;
+        }
     } else {
-        let generator = VbNetGenerator::new(cli.namespace, compat_mode);
+        if cli.watch {
+            eprintln!("Warning: --watch only applies when --proto is a directory; ignoring.");
+        }
+
         let parser = ProtoParser::new();
+        registry::ProtoRegistry::load(std::slice::from_ref(&cli.proto), &cli.proto_path, &parser)?
+            .validate_references()?;
         let proto = parser.parse_file(&cli.proto)?;
-        let out_path = generator.generate_to_file(&proto, &cli.out)?;
-        println!("Generated VB.NET: {}", out_path.display());
-
-        // Generate JSON schema
-        let json_generator = json_schema_codegen::JsonSchemaGenerator::new();
-        match json_generator.generate_to_file(&proto, &cli.out) {
-            Ok(json_path) => println!("Generated JSON Schema: {}", json_path.display()),
-            Err(e) => eprintln!("Warning: JSON schema generation failed: {}", e),
+
+        let file_name = std::path::Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let (extension, content) = match cli.target {
+            Target::Vbnet => (
+                "vb",
+                VbNetGenerator::with_compression_threshold(
+                    cli.namespace.clone(),
+                    compat_mode,
+                    cli.streaming,
+                    cli.wire_protocol,
+                    cli.enable_compression,
+                    cli.serialization_format,
+                    cli.enable_retry,
+                    cli.generation_target,
+                    cli.credential_mode,
+                    naming.clone(),
+                    cli.compression_threshold_bytes,
+                )
+                .generate_code(&proto)?,
+            ),
+            Target::Rust => ("rs", RustGenerator::new(cli.namespace.clone()).generate_code(&proto)?),
+            Target::Csharp => ("cs", CSharpGenerator::new(cli.namespace.clone(), compat_mode).generate_code(&proto)?),
+        };
+        let planned = PlannedFile { path: cli.out.join(format!("{}.{}", file_name, extension)), content };
+
+        if cli.check {
+            if emit_target {
+                let existing = check::scan_existing_outputs(&cli.out, extension);
+                let (check_report, first_diff) = check::run(std::slice::from_ref(&planned), &existing);
+                print_check_report(&check_report, first_diff.as_deref());
+                if !check_report.is_clean() {
+                    std::process::exit(1);
+                }
+            } else {
+                println!("Up to date.");
+            }
+            return Ok(());
+        }
+
+        fs::create_dir_all(&cli.out)?;
+        if emit_target {
+            fs::write(&planned.path, &planned.content)?;
+            if cli.format == Format::Json {
+                emit_generation_report(std::slice::from_ref(&proto), &cli.out, cli.target, cli.namespace.as_deref());
+            } else {
+                println!("Generated {}: {}", target_label(cli.target), planned.path.display());
+            }
+        }
+
+        // Generate JSON schema, unless --emit excludes it.
+        if emit_json {
+            let json_generator = json_schema_codegen::JsonSchemaGenerator::new();
+            match json_generator.generate_to_file(&proto, &cli.out) {
+                Ok(json_path) => println!("Generated JSON Schema: {}", json_path.display()),
+                Err(e) => eprintln!("Warning: JSON schema generation failed: {}", e),
+            }
+        }
+
+        // Generate OpenAPI 3.1 document, unless --emit excludes it.
+        if emit_openapi {
+            let openapi_generator = openapi_codegen::OpenApiGenerator::new();
+            match openapi_generator.generate_to_file(&proto, &cli.out) {
+                Ok(openapi_path) => println!("Generated OpenAPI document: {}", openapi_path.display()),
+                Err(e) => eprintln!("Warning: OpenAPI generation failed: {}", e),
+            }
+        }
+
+        // Generate the proto IR, unless --emit excludes it.
+        if emit_ir {
+            let ir_generator = ir_codegen::IrGenerator::new();
+            match ir_generator.generate_to_file(&proto, &cli.out) {
+                Ok(ir_path) => println!("Generated IR: {}", ir_path.display()),
+                Err(e) => eprintln!("Warning: IR generation failed: {}", e),
+            }
+        }
+
+        // Generate a Graphviz DOT document, unless --emit excludes it.
+        if emit_dot {
+            let dot_generator = dot::DotGenerator::new();
+            match dot_generator.generate_to_file(&proto, &cli.out) {
+                Ok(dot_path) => println!("Generated DOT graph: {}", dot_path.display()),
+                Err(e) => eprintln!("Warning: DOT generation failed: {}", e),
+            }
+        }
+
+        // Render --template-dir, unless --emit excludes it.
+        if emit_template {
+            let generator = template_generator.as_ref().expect("validated above: --emit template requires --template-dir");
+            let output_path = generator.generate_to_file(&proto, &cli.out)?;
+            println!("Generated from template: {}", output_path.display());
         }
     }
 
@@ -0,0 +1,278 @@
+use crate::codegen::CodeGenerator;
+use crate::error::Result;
+use crate::json_schema_codegen::JsonSchemaGenerator;
+use crate::parser::ProtoParser;
+use crate::types::{PackageName, ProtoFile, ProtoRpc, ProtoService, ProtoType};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Emits an OpenAPI 3.1 document (`openapi.json`) describing the same
+/// unary HTTP/JSON surface the VB.NET `FooClient` HTTP proxy speaks, so a
+/// Swagger UI and the generated client always describe the same contract.
+/// OpenAPI 3.1 schemas are JSON Schema 2020-12, so every message/enum
+/// schema is built by reusing
+/// [`JsonSchemaGenerator::build_message_schema`]/
+/// [`JsonSchemaGenerator::build_enum_schema`] verbatim; only the `$ref`
+/// prefix differs (`#/components/schemas/...` instead of `#/$defs/...`).
+pub struct OpenApiGenerator {
+    json_schema: JsonSchemaGenerator,
+}
+
+impl OpenApiGenerator {
+    pub fn new() -> Self {
+        Self { json_schema: JsonSchemaGenerator::new() }
+    }
+
+    /// `#/components/schemas/...` (or `other.json#/components/schemas/...`
+    /// for a cross-file reference) for a message/enum field type, matching
+    /// [`JsonSchemaGenerator::qualify_json_schema_ref`] but under OpenAPI's
+    /// `components/schemas` root instead of `$defs`.
+    fn schema_ref(&self, proto_type: &ProtoType, current_pkg: Option<&PackageName>) -> String {
+        self.json_schema
+            .qualify_json_schema_ref(proto_type, current_pkg)
+            .replacen("#/$defs/", "#/components/schemas/", 1)
+    }
+
+    /// One POST `PathItem` for a unary RPC: `/{package}.{Service}/{Method}`,
+    /// with the request message as the JSON request body and the response
+    /// message as the `200` response.
+    fn build_operation(&self, service: &ProtoService, rpc: &ProtoRpc, current_pkg: Option<&PackageName>) -> Value {
+        let request_ref = self.schema_ref(rpc.input_type(), current_pkg);
+        let response_ref = self.schema_ref(rpc.output_type(), current_pkg);
+
+        json!({
+            "post": {
+                "operationId": format!("{}_{}", service.name().as_str(), rpc.name().as_str()),
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "application/json": {
+                            "schema": {"$ref": request_ref}
+                        }
+                    }
+                },
+                "responses": {
+                    "200": {
+                        "description": "Successful response",
+                        "content": {
+                            "application/json": {
+                                "schema": {"$ref": response_ref}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    /// Every service's unary RPCs as OpenAPI paths. Client- and
+    /// bidirectional-streaming RPCs have no unary HTTP/JSON shape, so
+    /// (mirroring the VB.NET generator's default, unary-only output) they
+    /// aren't represented here.
+    fn build_paths(&self, proto: &ProtoFile) -> Map<String, Value> {
+        let mut paths = Map::new();
+
+        for service in proto.services() {
+            let service_fqn = match proto.package() {
+                Some(pkg) => format!("{}.{}", pkg.as_str(), service.name().as_str()),
+                None => service.name().as_str().to_string(),
+            };
+
+            for rpc in service.unary_rpcs() {
+                let path = format!("/{}/{}", service_fqn, rpc.name().as_str());
+                paths.insert(path, self.build_operation(service, rpc, proto.package()));
+            }
+        }
+
+        paths
+    }
+
+    /// Every message/enum in `proto` as a `components/schemas` entry,
+    /// reusing the JSON Schema generator's own builders so the two outputs
+    /// never drift apart.
+    fn build_schemas(&self, proto: &ProtoFile) -> Result<Map<String, Value>> {
+        let mut defs = HashMap::new();
+
+        for proto_enum in proto.enums().values() {
+            defs.insert(proto_enum.name().as_str().to_string(), self.json_schema.build_enum_schema(proto_enum));
+        }
+
+        for msg in proto.messages().values() {
+            self.json_schema.build_message_schema(msg, &[], &mut defs, proto.package())?;
+        }
+
+        Ok(defs.into_iter().map(|(name, schema)| (name, rewrite_defs_to_components(schema))).collect())
+    }
+}
+
+/// Recursively rewrite every `$ref` from `#/$defs/...` to
+/// `#/components/schemas/...`, since [`JsonSchemaGenerator::build_message_schema`]
+/// always emits the former.
+fn rewrite_defs_to_components(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, val)| {
+                    if key == "$ref" {
+                        if let Value::String(s) = val {
+                            return (key, Value::String(s.replacen("#/$defs/", "#/components/schemas/", 1)));
+                        }
+                        return (key, val);
+                    }
+                    (key, rewrite_defs_to_components(val))
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(rewrite_defs_to_components).collect()),
+        other => other,
+    }
+}
+
+impl CodeGenerator for OpenApiGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        // One document per proto file, alongside the JSON schemas in their
+        // own `json/` subdirectory (see `JsonSchemaGenerator::generate_to_file`).
+        let openapi_dir = output_dir.join("openapi");
+        fs::create_dir_all(&openapi_dir)?;
+
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let json_string = self.generate_code(proto)?;
+
+        let output_path = openapi_dir.join(format!("{}.json", base_name));
+        fs::write(&output_path, json_string)?;
+
+        Ok(output_path)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        let base_name = Path::new(proto.file_name())
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+
+        let mut title = format!("{} HTTP proxy API", base_name);
+        if let Some(pkg) = proto.package() {
+            title.push_str(&format!(" (package: {})", pkg.as_str()));
+        }
+
+        let spec = json!({
+            "openapi": "3.1.0",
+            "info": {
+                "title": title,
+                "version": "1.0.0"
+            },
+            "paths": self.build_paths(proto),
+            "components": {
+                "schemas": self.build_schemas(proto)?
+            }
+        });
+
+        Ok(serde_json::to_string_pretty(&spec)?)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn description(&self) -> &'static str {
+        "OpenAPI 3.1 document (openapi.json) describing the unary HTTP/JSON proxy surface"
+    }
+}
+
+/// Generate `openapi.json` for every proto file under a directory,
+/// mirroring [`crate::json_schema_codegen::generate_json_schemas_for_directory`]'s
+/// per-file fan-out and error handling.
+pub fn generate_openapi_specs_for_directory(
+    proto_files: &[PathBuf],
+    parser: &ProtoParser,
+    output_dir: &Path,
+) -> Vec<Result<PathBuf>> {
+    let generator = OpenApiGenerator::new();
+
+    proto_files
+        .iter()
+        .map(|proto_file| {
+            let proto = parser.parse_file(proto_file)?;
+            generator.generate_to_file(&proto, output_dir)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        Identifier, ProtoFieldBuilder, ProtoFileBuilder, ProtoMessageBuilder, ProtoRpcBuilder, ProtoServiceBuilder,
+        ScalarType,
+    };
+
+    fn test_proto() -> ProtoFile {
+        let hello_request = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloRequest").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("user_name").unwrap())
+                .field_type(ProtoType::Scalar(ScalarType::String))
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let hello_reply = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloReply").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("message").unwrap())
+                .field_type(ProtoType::Scalar(ScalarType::String))
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let say_hello_rpc = ProtoRpcBuilder::default()
+            .name(Identifier::new("SayHello").unwrap())
+            .input_type(ProtoType::Message { name: "HelloRequest".to_string(), package: None })
+            .output_type(ProtoType::Message { name: "HelloReply".to_string(), package: None })
+            .build()
+            .unwrap();
+
+        let greeter_service =
+            ProtoServiceBuilder::default().name(Identifier::new("Greeter").unwrap()).rpcs(vec![say_hello_rpc]).build().unwrap();
+
+        let mut messages = HashMap::new();
+        messages.insert("HelloRequest".to_string(), hello_request);
+        messages.insert("HelloReply".to_string(), hello_reply);
+
+        ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .messages(messages)
+            .services(vec![greeter_service])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_openapi_document_shape() {
+        let proto = test_proto();
+        let generator = OpenApiGenerator::new();
+        let code = generator.generate_code(&proto).unwrap();
+        let doc: Value = serde_json::from_str(&code).unwrap();
+
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert!(doc["paths"]["/helloworld.Greeter/SayHello"]["post"].is_object());
+
+        let request_schema = &doc["paths"]["/helloworld.Greeter/SayHello"]["post"]["requestBody"]["content"]["application/json"]
+            ["schema"]["$ref"];
+        assert_eq!(request_schema, "#/components/schemas/HelloRequest");
+
+        assert!(doc["components"]["schemas"]["HelloRequest"].is_object());
+        assert!(doc["components"]["schemas"]["HelloReply"].is_object());
+    }
+}
@@ -1,35 +1,29 @@
 use crate::error::{Error, Result};
 use crate::types::*;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until, take_while};
+use nom::character::complete::{char, digit1, multispace1, satisfy};
+use nom::combinator::{map, map_res, opt, recognize};
+use nom::multi::many0;
+use nom::sequence::{delimited, pair};
+use nom::IResult;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-/// Proto file parser with functional parsing approach
-pub struct ProtoParser {
-    // Compiled regexes for efficient parsing
-    comment_re: Regex,
-    whitespace_re: Regex,
-    package_re: Regex,
-    enum_value_re: Regex,
-    field_re: Regex,
-    rpc_re: Regex,
-}
+type PResult<'a, T> = IResult<&'a str, T, nom::error::Error<&'a str>>;
 
-static BLOCK_KEYWORD_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"\b(\w+)\s+([A-Za-z_][\w]*)\s*\{").unwrap());
+/// Proto file parser, built from small `nom` combinators rather than regex
+/// pattern-matching: source is tokenized properly (strings, comments and
+/// nested blocks are understood structurally), then assembled into the
+/// same `ProtoFile`/`ProtoMessage`/... domain types the rest of the crate
+/// already works with.
+#[derive(Default)]
+pub struct ProtoParser;
 
 impl ProtoParser {
     pub fn new() -> Self {
-        Self {
-            comment_re: Regex::new(r"//.*").unwrap(),
-            whitespace_re: Regex::new(r"\s+").unwrap(),
-            package_re: Regex::new(r"\bpackage\s+([a-zA-Z_][\w\.]*)\s*;").unwrap(),
-            enum_value_re: Regex::new(r"([A-Za-z_][\w]*)\s*=\s*(\d+)\s*;").unwrap(),
-            field_re: Regex::new(r"(repeated\s+)?([A-Za-z_][\w\.]*)\s+([A-Za-z_][\w]*)\s*=\s*(\d+)\s*;").unwrap(),
-            rpc_re: Regex::new(r"\brpc\s+([A-Za-z_][\w]*)\s*\(\s*(stream\s+)?([A-Za-z_][\w\.]*)\s*\)\s*returns\s*\(\s*(stream\s+)?([A-Za-z_][\w\.]*)\s*\)\s*\{?\s*\}?").unwrap(),
-        }
+        Self
     }
 
     /// Parse a proto file from the given path
@@ -42,37 +36,37 @@ impl ProtoParser {
 
     /// Parse proto content with the given file path for error reporting
     pub fn parse_content(&self, content: &str, proto_path: &Path) -> Result<ProtoFile> {
-        // Clean and normalize the content
-        let cleaned_content = self.preprocess_content(content);
+        let (_, raw) =
+            proto_file_raw(content).map_err(|e| nom_error_to_parse_error(content, proto_path, e))?;
 
-        // Extract package
-        let package = self.extract_package(&cleaned_content)?;
+        // Pass one: collect every message/enum name declared anywhere in
+        // this file (including nested) so pass two (`build_message`/
+        // `resolve_type_name`) can tell an enum-typed field apart from a
+        // message-typed one instead of assuming every custom type is a
+        // message.
+        let known_types = collect_declared_types(&raw);
 
-        // Parse top-level blocks using functional approach
-        let blocks = self.extract_blocks(&cleaned_content);
+        let messages = raw
+            .messages
+            .into_iter()
+            .map(|m| {
+                let name = m.name.clone();
+                Ok((name, build_message(m, &known_types)?))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
 
-        let mut messages = HashMap::new();
-        let mut enums = HashMap::new();
-        let mut services = Vec::new();
+        let enums = raw
+            .enums
+            .into_iter()
+            .map(|e| {
+                let name = e.name.clone();
+                Ok((name, build_enum(e)?))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
 
-        // Process blocks using iterator chains
-        for (block_type, name, body) in blocks {
-            match block_type.as_str() {
-                "message" => {
-                    let message = self.parse_message(&name, &body, &[])?;
-                    messages.insert(name, message);
-                }
-                "enum" => {
-                    let proto_enum = self.parse_enum(&name, &body)?;
-                    enums.insert(name, proto_enum);
-                }
-                "service" => {
-                    let service = self.parse_service(&name, &body)?;
-                    services.push(service);
-                }
-                _ => {} // Ignore unknown blocks
-            }
-        }
+        let services = raw.services.into_iter().map(build_service).collect::<Result<Vec<_>>>()?;
+
+        let package = raw.package.map(PackageName::new).transpose()?;
 
         let file_name = proto_path
             .file_name()
@@ -86,285 +80,932 @@ impl ProtoParser {
             .messages(messages)
             .enums(enums)
             .services(services)
+            .imports(raw.imports)
             .build()
-            .map_err(|e| {
-                Error::parse_error(proto_path, format!("Failed to build proto file: {}", e))
-            })
+            .map_err(|e| Error::parse_error(proto_path, format!("Failed to build proto file: {}", e)))
     }
+}
 
-    /// Preprocess content by removing comments and normalizing whitespace
-    fn preprocess_content(&self, content: &str) -> String {
-        let no_comments = self.comment_re.replace_all(content, "");
-        self.whitespace_re
-            .replace_all(&no_comments, " ")
-            .to_string()
+impl Default for ProtoParser {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+// ---------------------------------------------------------------------
+// Raw (unvalidated) AST produced by the grammar below. Turning this into
+// the crate's domain types (with identifier/package validation via their
+// builders) happens afterwards, in `build_message`/`build_enum`/etc. -
+// keeping the nom combinators themselves free of `Result<_, crate::error::Error>`.
+// ---------------------------------------------------------------------
+
+enum RawFieldType {
+    Named(String),
+    Map { key: String, value: String },
+}
+
+struct RawField {
+    repeated: bool,
+    /// Set for a proto3 `optional` scalar/message field, i.e. one whose
+    /// presence is explicitly tracked rather than implied by a non-default
+    /// value. Never set for `map`/`repeated` fields, which proto3 doesn't
+    /// allow `optional` on.
+    optional: bool,
+    type_name: RawFieldType,
+    name: String,
+    number: u32,
+    docs: Option<String>,
+}
+
+struct RawOneof {
+    name: String,
+    fields: Vec<RawField>,
+    docs: Option<String>,
+}
+
+#[derive(Default)]
+struct RawMessage {
+    name: String,
+    fields: Vec<RawField>,
+    nested_messages: Vec<RawMessage>,
+    oneofs: Vec<RawOneof>,
+    /// Enums declared inside this message. `ProtoMessage` has nowhere to
+    /// keep these (matching the old parser's behavior of dropping them),
+    /// but pass one still needs their names to tell an enum-typed field
+    /// apart from a message-typed one - see `collect_declared_types`.
+    nested_enums: Vec<RawEnum>,
+    docs: Option<String>,
+}
+
+#[derive(Default)]
+struct RawEnum {
+    name: String,
+    values: Vec<(String, i32, Option<String>)>,
+    docs: Option<String>,
+}
+
+struct RawRpc {
+    name: String,
+    client_streaming: bool,
+    input_type: String,
+    server_streaming: bool,
+    output_type: String,
+    docs: Option<String>,
+}
+
+#[derive(Default)]
+struct RawService {
+    name: String,
+    rpcs: Vec<RawRpc>,
+    docs: Option<String>,
+}
+
+#[derive(Default)]
+struct RawFile {
+    package: Option<String>,
+    imports: Vec<String>,
+    messages: Vec<RawMessage>,
+    enums: Vec<RawEnum>,
+    services: Vec<RawService>,
+}
+
+// ---------------------------------------------------------------------
+// Lexical tokens
+// ---------------------------------------------------------------------
 
-    /// Extract package name
-    fn extract_package(&self, content: &str) -> Result<Option<PackageName>> {
-        self.package_re
-            .captures(content)
-            .and_then(|caps| caps.get(1))
-            .map(|m| PackageName::new(m.as_str()))
-            .transpose()
+/// Consume whitespace, `//` line comments, and `/* */` block comments.
+/// Comments are recognized structurally here rather than stripped up
+/// front, so a `//` or `{`/`}` inside a string literal is never mistaken
+/// for one.
+fn ws(input: &str) -> PResult<'_, ()> {
+    let mut rest = input;
+    loop {
+        if let Ok((r, _)) = multispace1::<&str, nom::error::Error<&str>>(rest) {
+            rest = r;
+            continue;
+        }
+        if let Ok((r, _)) = line_comment(rest) {
+            rest = r;
+            continue;
+        }
+        if let Ok((r, _)) = block_comment(rest) {
+            rest = r;
+            continue;
+        }
+        break;
     }
+    Ok((rest, ()))
+}
 
-    /// Extract top-level blocks (messages, enums, services) using functional parsing
-    fn extract_blocks(&self, content: &str) -> Vec<(String, String, String)> {
-        let mut blocks = Vec::new();
-        let chars: Vec<char> = content.chars().collect();
-        let mut pos = 0;
-
-        while pos < chars.len() {
-            if let Some(captures) = BLOCK_KEYWORD_RE.captures(&content[pos..]) {
-                let full_match = captures.get(0).unwrap();
-                let keyword = captures.get(1).unwrap().as_str().to_string();
-                let name = captures.get(2).unwrap().as_str().to_string();
-
-                let brace_start = pos + full_match.end() - 1;
-
-                if let Some(body) = self.extract_balanced_block(&chars, brace_start) {
-                    blocks.push((keyword, name, body.clone()));
-                    pos = brace_start + body.len() + 2; // Skip past closing brace
-                } else {
-                    pos += full_match.end();
-                }
-            } else {
-                pos += 1;
+fn line_comment(input: &str) -> PResult<'_, ()> {
+    map(line_comment_content, |_| ())(input)
+}
+
+fn block_comment(input: &str) -> PResult<'_, ()> {
+    map(block_comment_content, |_| ())(input)
+}
+
+fn line_comment_content(input: &str) -> PResult<'_, &str> {
+    let (input, _) = tag("//")(input)?;
+    take_while(|c| c != '\n')(input)
+}
+
+fn block_comment_content(input: &str) -> PResult<'_, &str> {
+    delimited(tag("/*"), take_until("*/"), tag("*/"))(input)
+}
+
+/// Consume leading whitespace/comments like `ws`, but return the text of
+/// the comment block immediately touching what follows, if any - this
+/// becomes the next declaration's leading doc. A blank line severs the
+/// chain, so a comment block separated from the declaration by blank
+/// space is treated as unrelated prose rather than its documentation,
+/// matching the convention protoc's descriptor uses.
+fn leading_doc(input: &str) -> PResult<'_, Option<String>> {
+    let mut rest = input;
+    let mut lines: Vec<String> = Vec::new();
+    loop {
+        if let Ok((r, matched)) = multispace1::<&str, nom::error::Error<&str>>(rest) {
+            if matched.matches('\n').count() >= 2 {
+                lines.clear();
             }
+            rest = r;
+            continue;
+        }
+        if let Ok((r, text)) = line_comment_content(rest) {
+            lines.push(text.trim().to_string());
+            rest = r;
+            continue;
         }
+        if let Ok((r, text)) = block_comment_content(rest) {
+            lines.push(text.trim().to_string());
+            rest = r;
+            continue;
+        }
+        break;
+    }
+    let docs = if lines.is_empty() { None } else { Some(lines.join("\n")) };
+    Ok((rest, docs))
+}
 
-        blocks
+/// If a `// ...` line comment follows on the same line (only spaces/tabs
+/// in between), consume and return it as a trailing doc - protoc's
+/// same-line-comment convention for fields and enum values. Consumes
+/// nothing if the line ends (or the file ends) before a comment starts.
+fn trailing_same_line_doc(input: &str) -> PResult<'_, Option<String>> {
+    let trimmed = input.trim_start_matches([' ', '\t', '\r']);
+    if trimmed.starts_with("//") {
+        let (rest, text) = line_comment_content(trimmed)?;
+        return Ok((rest, Some(text.trim().to_string())));
     }
+    Ok((input, None))
+}
+
+/// Prefer a same-line trailing comment over a leading comment block, so
+/// `ProtoField`/`ProtoEnum` value's single `docs` slot holds whichever is
+/// more specific to that declaration.
+fn merge_docs(leading: Option<String>, trailing: Option<String>) -> Option<String> {
+    trailing.or(leading)
+}
+
+/// Run `inner` after skipping leading whitespace/comments.
+fn token<'a, O, F>(mut inner: F) -> impl FnMut(&'a str) -> PResult<'a, O>
+where
+    F: FnMut(&'a str) -> PResult<'a, O>,
+{
+    move |input: &'a str| {
+        let (input, _) = ws(input)?;
+        inner(input)
+    }
+}
+
+fn punct<'a>(ch: char) -> impl FnMut(&'a str) -> PResult<'a, char> {
+    token(char(ch))
+}
+
+/// Match an exact keyword, rejecting it if it's really a prefix of a
+/// longer identifier (e.g. `messageType` is not the keyword `message`).
+fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        let (after_ws, _) = ws(input)?;
+        let (rest, matched) = tag(word)(after_ws)?;
+        if matches!(rest.chars().next(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            return Err(nom::Err::Error(nom::error::Error {
+                input: after_ws,
+                code: nom::error::ErrorKind::Tag,
+            }));
+        }
+        Ok((rest, matched))
+    }
+}
+
+fn identifier_raw(input: &str) -> PResult<'_, &str> {
+    recognize(pair(
+        satisfy(|c: char| c.is_ascii_alphabetic() || c == '_'),
+        take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+    ))(input)
+}
+
+fn identifier(input: &str) -> PResult<'_, &str> {
+    token(identifier_raw)(input)
+}
+
+/// A (possibly dotted) type reference, e.g. `string` or `common.Ticker`.
+/// No whitespace is allowed around the dots, matching real proto syntax.
+fn qualified_name(input: &str) -> PResult<'_, String> {
+    let (input, _) = ws(input)?;
+    let (input, raw) = recognize(pair(identifier_raw, many0(pair(char('.'), identifier_raw))))(input)?;
+    Ok((input, raw.to_string()))
+}
+
+fn string_literal(input: &str) -> PResult<'_, String> {
+    let (input, _) = ws(input)?;
+    let (input, _) = char('"')(input)?;
 
-    /// Extract content between balanced braces
-    fn extract_balanced_block(&self, chars: &[char], brace_start: usize) -> Option<String> {
-        if brace_start >= chars.len() || chars[brace_start] != '{' {
-            return None;
+    let mut result = String::new();
+    let mut chars = input.char_indices();
+    loop {
+        match chars.next() {
+            Some((i, '"')) => return Ok((&input[i + 1..], result)),
+            Some((_, '\\')) => match chars.next() {
+                Some((_, 'n')) => result.push('\n'),
+                Some((_, 't')) => result.push('\t'),
+                Some((_, 'r')) => result.push('\r'),
+                Some((_, c)) => result.push(c),
+                None => {
+                    return Err(nom::Err::Failure(nom::error::Error {
+                        input,
+                        code: nom::error::ErrorKind::Escaped,
+                    }))
+                }
+            },
+            Some((_, c)) => result.push(c),
+            None => {
+                return Err(nom::Err::Failure(nom::error::Error {
+                    input,
+                    code: nom::error::ErrorKind::Char,
+                }))
+            }
         }
+    }
+}
+
+fn field_number(input: &str) -> PResult<'_, u32> {
+    map_res(token(digit1), |s: &str| s.parse::<u32>())(input)
+}
 
-        let mut depth = 1;
-        let mut pos = brace_start + 1;
+fn integer_literal(input: &str) -> PResult<'_, i32> {
+    map_res(token(recognize(pair(opt(char('-')), digit1))), |s: &str| s.parse::<i32>())(input)
+}
 
-        while pos < chars.len() && depth > 0 {
-            match chars[pos] {
-                '{' => depth += 1,
-                '}' => depth -= 1,
-                _ => {}
+/// Skip from just past an opening bracket to its matching close,
+/// tracking nesting of the same bracket kind and ignoring bracket
+/// characters that appear inside a string literal.
+fn skip_balanced(input: &str, open: char, close: char) -> PResult<'_, ()> {
+    let bytes = input.as_bytes();
+    let mut i = 0usize;
+    let mut depth = 1i32;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if in_string {
+            if c == '\\' {
+                i += 2;
+                continue;
+            }
+            if c == '"' {
+                in_string = false;
             }
-            pos += 1;
+            i += 1;
+            continue;
         }
+        if c == '"' {
+            in_string = true;
+        } else if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Ok((&input[i + 1..], ()));
+            }
+        }
+        i += 1;
+    }
+    Err(nom::Err::Failure(nom::error::Error {
+        input,
+        code: nom::error::ErrorKind::Eof,
+    }))
+}
 
-        if depth == 0 {
-            Some(chars[(brace_start + 1)..(pos - 1)].iter().collect())
-        } else {
-            None
+/// Skip a `[...]` field/enum-value option list.
+fn field_options(input: &str) -> PResult<'_, ()> {
+    let (input, _) = punct('[')(input)?;
+    skip_balanced(input, '[', ']')
+}
+
+/// Skip to (and past) the next top-level `;`, tracking bracket nesting and
+/// string literals so an embedded `;`, `{`, or `}` doesn't end the scan
+/// early. Used for statements this parser recognizes but doesn't model in
+/// detail, like `option ...;` and `reserved ...;`.
+fn skip_until_semi(input: &str) -> PResult<'_, ()> {
+    let bytes = input.as_bytes();
+    let mut i = 0usize;
+    let mut depth = 0i32;
+    let mut in_string = false;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if in_string {
+            if c == '\\' {
+                i += 2;
+                continue;
+            }
+            if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
         }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ';' if depth <= 0 => return Ok((&input[i + 1..], ())),
+            _ => {}
+        }
+        i += 1;
     }
+    Err(nom::Err::Failure(nom::error::Error {
+        input,
+        code: nom::error::ErrorKind::Eof,
+    }))
+}
 
-    /// Parse a message definition using builder pattern
-    fn parse_message(
-        &self,
-        name: &str,
-        body: &str,
-        parent_path: &[String],
-    ) -> Result<ProtoMessage> {
-        let identifier = Identifier::new(name)?;
-
-        // Extract nested messages first
-        let nested_blocks = self.extract_blocks(body);
-        let nested_messages: HashMap<String, ProtoMessage> = nested_blocks
-            .into_iter()
-            .filter(|(block_type, _, _)| block_type == "message")
-            .map(|(_, nested_name, nested_body)| {
-                let mut current_path = parent_path.to_vec();
-                current_path.push(name.to_string());
-                let nested_msg = self.parse_message(&nested_name, &nested_body, &current_path)?;
-                Ok((nested_name, nested_msg))
-            })
-            .collect::<Result<HashMap<_, _>>>()?;
+fn semi(input: &str) -> PResult<'_, char> {
+    punct(';')(input)
+}
 
-        // Remove nested message blocks from body for field parsing
-        let field_body = self.remove_nested_blocks(body);
+fn option_stmt(input: &str) -> PResult<'_, ()> {
+    let (input, _) = keyword("option")(input)?;
+    skip_until_semi(input)
+}
 
-        // Parse fields using iterator chain
-        let fields: Vec<ProtoField> = self
-            .field_re
-            .captures_iter(&field_body)
-            .map(|caps| self.parse_field_from_captures(caps))
-            .collect::<Result<Vec<_>>>()?;
+fn reserved_stmt(input: &str) -> PResult<'_, ()> {
+    let (input, _) = keyword("reserved")(input)?;
+    skip_until_semi(input)
+}
 
-        ProtoMessageBuilder::default()
-            .name(identifier)
-            .fields(fields)
-            .nested_messages(nested_messages)
-            .build()
-            .map_err(|e| Error::validation_error(format!("Invalid message {}: {}", name, e)))
+// ---------------------------------------------------------------------
+// Grammar
+// ---------------------------------------------------------------------
+
+fn syntax_stmt(input: &str) -> PResult<'_, ()> {
+    let (input, _) = keyword("syntax")(input)?;
+    let (input, _) = punct('=')(input)?;
+    let (input, _) = string_literal(input)?;
+    let (input, _) = semi(input)?;
+    Ok((input, ()))
+}
+
+fn package_stmt(input: &str) -> PResult<'_, String> {
+    let (input, _) = keyword("package")(input)?;
+    let (input, name) = qualified_name(input)?;
+    let (input, _) = semi(input)?;
+    Ok((input, name))
+}
+
+fn import_stmt(input: &str) -> PResult<'_, String> {
+    let (input, _) = keyword("import")(input)?;
+    let (input, _) = opt(alt((keyword("public"), keyword("weak"))))(input)?;
+    let (input, path) = string_literal(input)?;
+    let (input, _) = semi(input)?;
+    Ok((input, path))
+}
+
+/// A proto3 `map<K, V> name = N;` field. Kept as its own alternative
+/// (tried before `plain_field`) since a map's angle-bracket type syntax
+/// doesn't fit the `[repeated] Type name` shape plain fields use.
+fn map_field(input: &str) -> PResult<'_, RawField> {
+    let (input, _) = keyword("map")(input)?;
+    let (input, _) = punct('<')(input)?;
+    let (input, key) = qualified_name(input)?;
+    let (input, _) = punct(',')(input)?;
+    let (input, value) = qualified_name(input)?;
+    let (input, _) = punct('>')(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('=')(input)?;
+    let (input, number) = field_number(input)?;
+    let (input, _) = opt(field_options)(input)?;
+    let (input, _) = semi(input)?;
+    let (input, docs) = trailing_same_line_doc(input)?;
+    Ok((
+        input,
+        RawField {
+            repeated: false,
+            optional: false,
+            type_name: RawFieldType::Map { key, value },
+            name: name.to_string(),
+            number,
+            docs,
+        },
+    ))
+}
+
+fn plain_field(input: &str) -> PResult<'_, RawField> {
+    // proto3 allows at most one of `repeated`/`optional` before the type.
+    let (input, modifier) = opt(alt((keyword("repeated"), keyword("optional"))))(input)?;
+    let (input, type_name) = qualified_name(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('=')(input)?;
+    let (input, number) = field_number(input)?;
+    let (input, _) = opt(field_options)(input)?;
+    let (input, _) = semi(input)?;
+    let (input, docs) = trailing_same_line_doc(input)?;
+    Ok((
+        input,
+        RawField {
+            repeated: modifier == Some("repeated"),
+            optional: modifier == Some("optional"),
+            type_name: RawFieldType::Named(type_name),
+            name: name.to_string(),
+            number,
+            docs,
+        },
+    ))
+}
+
+fn field_stmt(input: &str) -> PResult<'_, RawField> {
+    alt((map_field, plain_field))(input)
+}
+
+/// A `oneof name { ... }` group. Members parse as ordinary fields; `oneof`
+/// blocks are field containers, not something to be skipped like
+/// `reserved`/`option`, so each member ends up in [`RawOneof::fields`]
+/// rather than being discarded.
+fn oneof_item(input: &str) -> PResult<'_, Option<RawField>> {
+    let (input, leading) = leading_doc(input)?;
+    let (input, item) = alt((
+        map(field_stmt, Some),
+        map(option_stmt, |_| None),
+        map(semi, |_| None),
+    ))(input)?;
+    let item = item.map(|mut f| {
+        f.docs = merge_docs(leading, f.docs.take());
+        f
+    });
+    Ok((input, item))
+}
+
+fn oneof_def(input: &str) -> PResult<'_, RawOneof> {
+    let (input, _) = keyword("oneof")(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('{')(input)?;
+    let (input, items) = many0(oneof_item)(input)?;
+    let (input, _) = punct('}')(input)?;
+
+    let fields = items.into_iter().flatten().collect();
+    Ok((input, RawOneof { name: name.to_string(), fields, docs: None }))
+}
+
+enum MessageItem {
+    Field(RawField),
+    Nested(RawMessage),
+    NestedEnum(RawEnum),
+    Oneof(RawOneof),
+    Ignored,
+}
+
+fn message_item(input: &str) -> PResult<'_, MessageItem> {
+    let (input, leading) = leading_doc(input)?;
+    let (input, mut item) = alt((
+        map(message_def, MessageItem::Nested),
+        map(enum_def, MessageItem::NestedEnum),
+        map(oneof_def, MessageItem::Oneof),
+        map(field_stmt, MessageItem::Field),
+        map(option_stmt, |_| MessageItem::Ignored),
+        map(reserved_stmt, |_| MessageItem::Ignored),
+        map(semi, |_| MessageItem::Ignored),
+    ))(input)?;
+
+    match &mut item {
+        MessageItem::Field(f) => f.docs = merge_docs(leading, f.docs.take()),
+        MessageItem::Nested(m) => m.docs = merge_docs(leading, m.docs.take()),
+        MessageItem::NestedEnum(e) => e.docs = merge_docs(leading, e.docs.take()),
+        MessageItem::Oneof(o) => o.docs = merge_docs(leading, o.docs.take()),
+        MessageItem::Ignored => {}
     }
+    Ok((input, item))
+}
 
-    /// Remove nested message/enum blocks from content for field parsing
-    fn remove_nested_blocks(&self, content: &str) -> String {
-        // Simple approach: remove content between braces
-        let result = content.to_string();
-        let mut depth = 0;
-        let mut chars = result.chars().collect::<Vec<_>>();
-        let mut i = 0;
-
-        while i < chars.len() {
-            match chars[i] {
-                '{' => {
-                    if depth > 0 {
-                        chars[i] = ' '; // Replace with space
-                    }
-                    depth += 1;
-                }
-                '}' => {
-                    depth -= 1;
-                    if depth > 0 {
-                        chars[i] = ' '; // Replace with space
-                    }
-                }
-                _ if depth > 0 => {
-                    chars[i] = ' '; // Replace with space
-                }
-                _ => {}
-            }
-            i += 1;
+fn message_def(input: &str) -> PResult<'_, RawMessage> {
+    let (input, _) = keyword("message")(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('{')(input)?;
+    let (input, items) = many0(message_item)(input)?;
+    let (input, _) = punct('}')(input)?;
+
+    let mut message = RawMessage { name: name.to_string(), ..Default::default() };
+    for item in items {
+        match item {
+            MessageItem::Field(f) => message.fields.push(f),
+            MessageItem::Nested(m) => message.nested_messages.push(m),
+            MessageItem::NestedEnum(e) => message.nested_enums.push(e),
+            MessageItem::Oneof(o) => message.oneofs.push(o),
+            MessageItem::Ignored => {}
+        }
+    }
+    Ok((input, message))
+}
+
+fn enum_value_stmt(input: &str) -> PResult<'_, (String, i32, Option<String>)> {
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('=')(input)?;
+    let (input, value) = integer_literal(input)?;
+    let (input, _) = opt(field_options)(input)?;
+    let (input, _) = semi(input)?;
+    let (input, trailing) = trailing_same_line_doc(input)?;
+    Ok((input, (name.to_string(), value, trailing)))
+}
+
+fn enum_item(input: &str) -> PResult<'_, Option<(String, i32, Option<String>)>> {
+    let (input, leading) = leading_doc(input)?;
+    let (input, item) = alt((
+        map(enum_value_stmt, Some),
+        map(option_stmt, |_| None),
+        map(reserved_stmt, |_| None),
+        map(semi, |_| None),
+    ))(input)?;
+    let item = item.map(|(name, value, trailing)| (name, value, merge_docs(leading, trailing)));
+    Ok((input, item))
+}
+
+fn enum_def(input: &str) -> PResult<'_, RawEnum> {
+    let (input, _) = keyword("enum")(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('{')(input)?;
+    let (input, items) = many0(enum_item)(input)?;
+    let (input, _) = punct('}')(input)?;
+
+    let values = items.into_iter().flatten().collect();
+    Ok((input, RawEnum { name: name.to_string(), values, docs: None }))
+}
+
+/// An rpc's trailer is either a bare `;` or a `{ ... }` body for
+/// method-level options, which this parser doesn't model beyond skipping.
+fn rpc_trailer(input: &str) -> PResult<'_, ()> {
+    alt((
+        map(semi, |_| ()),
+        map(pair(punct('{'), many0(option_stmt)), |_| ()),
+    ))(input)
+    .and_then(|(input, ())| {
+        let (input, _) = opt(punct('}'))(input)?;
+        Ok((input, ()))
+    })
+}
+
+fn rpc_stmt(input: &str) -> PResult<'_, RawRpc> {
+    let (input, _) = keyword("rpc")(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('(')(input)?;
+    let (input, client_streaming) = opt(keyword("stream"))(input)?;
+    let (input, input_type) = qualified_name(input)?;
+    let (input, _) = punct(')')(input)?;
+    let (input, _) = keyword("returns")(input)?;
+    let (input, _) = punct('(')(input)?;
+    let (input, server_streaming) = opt(keyword("stream"))(input)?;
+    let (input, output_type) = qualified_name(input)?;
+    let (input, _) = punct(')')(input)?;
+    let (input, _) = rpc_trailer(input)?;
+    let (input, docs) = trailing_same_line_doc(input)?;
+
+    Ok((
+        input,
+        RawRpc {
+            name: name.to_string(),
+            client_streaming: client_streaming.is_some(),
+            input_type,
+            server_streaming: server_streaming.is_some(),
+            output_type,
+            docs,
+        },
+    ))
+}
+
+fn service_item(input: &str) -> PResult<'_, Option<RawRpc>> {
+    let (input, leading) = leading_doc(input)?;
+    let (input, item) = alt((map(rpc_stmt, Some), map(option_stmt, |_| None), map(semi, |_| None)))(input)?;
+    let item = item.map(|mut rpc| {
+        rpc.docs = merge_docs(leading, rpc.docs.take());
+        rpc
+    });
+    Ok((input, item))
+}
+
+fn service_def(input: &str) -> PResult<'_, RawService> {
+    let (input, _) = keyword("service")(input)?;
+    let (input, name) = identifier(input)?;
+    let (input, _) = punct('{')(input)?;
+    let (input, items) = many0(service_item)(input)?;
+    let (input, _) = punct('}')(input)?;
+
+    let rpcs = items.into_iter().flatten().collect();
+    Ok((input, RawService { name: name.to_string(), rpcs, docs: None }))
+}
+
+enum TopLevelItem {
+    Package(String),
+    Import(String),
+    Message(RawMessage),
+    Enum(RawEnum),
+    Service(RawService),
+    Ignored,
+}
+
+fn top_level_item(input: &str) -> PResult<'_, TopLevelItem> {
+    let (input, leading) = leading_doc(input)?;
+    let (input, mut item) = alt((
+        map(package_stmt, TopLevelItem::Package),
+        map(import_stmt, TopLevelItem::Import),
+        map(option_stmt, |_| TopLevelItem::Ignored),
+        map(semi, |_| TopLevelItem::Ignored),
+        map(message_def, TopLevelItem::Message),
+        map(enum_def, TopLevelItem::Enum),
+        map(service_def, TopLevelItem::Service),
+    ))(input)?;
+
+    match &mut item {
+        TopLevelItem::Message(m) => m.docs = merge_docs(leading, m.docs.take()),
+        TopLevelItem::Enum(e) => e.docs = merge_docs(leading, e.docs.take()),
+        TopLevelItem::Service(s) => s.docs = merge_docs(leading, s.docs.take()),
+        TopLevelItem::Package(_) | TopLevelItem::Import(_) | TopLevelItem::Ignored => {}
+    }
+    Ok((input, item))
+}
+
+fn proto_file_raw(input: &str) -> PResult<'_, RawFile> {
+    let (input, _) = opt(syntax_stmt)(input)?;
+    let (input, items) = many0(top_level_item)(input)?;
+    let (rest, _) = ws(input)?;
+    if !rest.is_empty() {
+        return Err(nom::Err::Failure(nom::error::Error {
+            input: rest,
+            code: nom::error::ErrorKind::Eof,
+        }));
+    }
+
+    let mut file = RawFile::default();
+    for item in items {
+        match item {
+            TopLevelItem::Package(p) => file.package = Some(p),
+            TopLevelItem::Import(i) => file.imports.push(i),
+            TopLevelItem::Message(m) => file.messages.push(m),
+            TopLevelItem::Enum(e) => file.enums.push(e),
+            TopLevelItem::Service(s) => file.services.push(s),
+            TopLevelItem::Ignored => {}
         }
+    }
+    Ok((rest, file))
+}
+
+// ---------------------------------------------------------------------
+// Raw AST -> domain types
+// ---------------------------------------------------------------------
+
+/// What a declared name in this file turned out to be, as collected by
+/// `collect_declared_types`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Message,
+    Enum,
+}
 
-        chars.into_iter().collect()
+/// Pass one: walk the raw AST and record every message/enum name declared
+/// anywhere in the file (including nested ones) so pass two can classify
+/// an unqualified field type correctly instead of assuming it's always a
+/// message. Names are tracked flat (not by nesting path), matching the
+/// rest of this parser's simplified same-file name handling.
+fn collect_declared_types(raw: &RawFile) -> HashMap<String, TypeKind> {
+    let mut known = HashMap::new();
+    for message in &raw.messages {
+        collect_types_from_message(message, &mut known);
     }
+    for e in &raw.enums {
+        known.insert(e.name.clone(), TypeKind::Enum);
+    }
+    known
+}
 
-    /// Parse a field from regex captures
-    fn parse_field_from_captures(&self, caps: regex::Captures) -> Result<ProtoField> {
-        let is_repeated = caps.get(1).is_some();
-        let type_str = caps.get(2).unwrap().as_str();
-        let field_name = caps.get(3).unwrap().as_str();
-        let field_number: u32 = caps
-            .get(4)
-            .unwrap()
-            .as_str()
-            .parse()
-            .map_err(|_| Error::validation_error("Invalid field number"))?;
-
-        let field_type = self.parse_proto_type(type_str, is_repeated)?;
-
-        ProtoFieldBuilder::default()
-            .name(Identifier::new(field_name)?)
-            .field_type(field_type)
-            .field_number(field_number)
-            .build()
-            .map_err(|e| Error::validation_error(format!("Invalid field {}: {}", field_name, e)))
+fn collect_types_from_message(message: &RawMessage, known: &mut HashMap<String, TypeKind>) {
+    known.insert(message.name.clone(), TypeKind::Message);
+    for nested in &message.nested_messages {
+        collect_types_from_message(nested, known);
+    }
+    for nested_enum in &message.nested_enums {
+        known.insert(nested_enum.name.clone(), TypeKind::Enum);
     }
+}
 
-    /// Parse a proto type from string representation
-    fn parse_proto_type(&self, type_str: &str, is_repeated: bool) -> Result<ProtoType> {
-        let base_type = if let Ok(scalar) = type_str.parse::<ScalarType>() {
-            ProtoType::Scalar(scalar)
-        } else if type_str.contains('.') {
-            // Qualified type (e.g., "common.Ticker")
-            let parts: Vec<&str> = type_str.split('.').collect();
-            if parts.len() >= 2 {
-                let package_parts = &parts[0..parts.len() - 1];
-                let type_name = parts[parts.len() - 1];
-                let package = if package_parts.is_empty() {
-                    None
-                } else {
-                    Some(PackageName::new(package_parts.join("."))?)
-                };
-                // Assume it's a message for now (could be enhanced to detect enums)
-                ProtoType::Message {
-                    name: type_name.to_string(),
-                    package,
-                }
-            } else {
-                return Err(Error::InvalidProtoType {
-                    proto_type: type_str.to_string(),
-                });
+/// Resolve an unqualified or qualified field type name against this
+/// file's own declared messages/enums (`known_types`), so a field whose
+/// type is actually an enum is recorded as `ProtoType::Enum` rather than
+/// always `ProtoType::Message`. A qualified (cross-package) name, or an
+/// unqualified one this file never declares, can't be classified here -
+/// that's the job of `ProtoRegistry`, which resolves references across
+/// the whole loaded file tree - so this falls back to the old
+/// message-assuming behavior and prints a warning so it's distinguishable
+/// from a confirmed resolution.
+fn resolve_type_name(type_str: &str, is_repeated: bool, known_types: &HashMap<String, TypeKind>) -> Result<ProtoType> {
+    if type_str.parse::<ScalarType>().is_err() && !type_str.contains('.') {
+        match known_types.get(type_str) {
+            Some(TypeKind::Enum) => {
+                let base = ProtoType::Enum { name: type_str.to_string(), package: None };
+                return Ok(if is_repeated { ProtoType::Repeated(Box::new(base)) } else { base });
             }
-        } else {
-            // Unqualified type - assume message in current package
-            ProtoType::Message {
-                name: type_str.to_string(),
-                package: None,
+            Some(TypeKind::Message) => {
+                let base = ProtoType::Message { name: type_str.to_string(), package: None };
+                return Ok(if is_repeated { ProtoType::Repeated(Box::new(base)) } else { base });
+            }
+            None => {
+                eprintln!(
+                    "Warning: could not classify type \"{}\" as a message or enum in this file; assuming message (cross-file references are resolved by ProtoRegistry)",
+                    type_str
+                );
             }
-        };
+        }
+    }
 
-        if is_repeated {
-            Ok(ProtoType::Repeated(Box::new(base_type)))
+    parse_proto_type(type_str, is_repeated)
+}
+
+fn parse_proto_type(type_str: &str, is_repeated: bool) -> Result<ProtoType> {
+    let base_type = if let Ok(scalar) = type_str.parse::<ScalarType>() {
+        ProtoType::Scalar(scalar)
+    } else if type_str.contains('.') {
+        let parts: Vec<&str> = type_str.split('.').collect();
+        if parts.len() >= 2 {
+            let package_parts = &parts[0..parts.len() - 1];
+            let type_name = parts[parts.len() - 1];
+            let package = if package_parts.is_empty() {
+                None
+            } else {
+                Some(PackageName::new(package_parts.join("."))?)
+            };
+            ProtoType::Message { name: type_name.to_string(), package }
         } else {
-            Ok(base_type)
+            return Err(Error::InvalidProtoType { proto_type: type_str.to_string() });
         }
+    } else {
+        ProtoType::Message { name: type_str.to_string(), package: None }
+    };
+
+    if is_repeated {
+        Ok(ProtoType::Repeated(Box::new(base_type)))
+    } else {
+        Ok(base_type)
     }
+}
 
-    /// Parse an enum definition
-    fn parse_enum(&self, name: &str, body: &str) -> Result<ProtoEnum> {
-        let identifier = Identifier::new(name)?;
-
-        let values: HashMap<String, i32> = self
-            .enum_value_re
-            .captures_iter(body)
-            .map(|caps| {
-                let key = caps.get(1).unwrap().as_str().to_string();
-                let val = caps
-                    .get(2)
-                    .unwrap()
-                    .as_str()
-                    .parse::<i32>()
-                    .map_err(|_| Error::validation_error("Invalid enum value"))?;
-                Ok((key, val))
-            })
-            .collect::<Result<HashMap<_, _>>>()?;
+fn build_field(raw: RawField, known_types: &HashMap<String, TypeKind>) -> Result<ProtoField> {
+    let field_type = match raw.type_name {
+        RawFieldType::Named(type_name) => resolve_type_name(&type_name, raw.repeated, known_types)?,
+        RawFieldType::Map { key, value } => {
+            let key_scalar = key
+                .parse::<ScalarType>()
+                .map_err(|_| Error::validation_error(format!("Invalid map key type: {}", key)))?;
+            let value_type = parse_proto_type(&value, false)?;
+            ProtoType::map(key_scalar, value_type)?
+        }
+    };
+    ProtoFieldBuilder::default()
+        .name(Identifier::new(&raw.name)?)
+        .field_type(field_type)
+        .field_number(raw.number)
+        .optional(raw.optional)
+        .docs(raw.docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid field {}: {}", raw.name, e)))
+}
 
-        ProtoEnumBuilder::default()
-            .name(identifier)
-            .values(values)
-            .build()
-            .map_err(|e| Error::validation_error(format!("Invalid enum {}: {}", name, e)))
-    }
+fn build_oneof(raw: RawOneof, known_types: &HashMap<String, TypeKind>) -> Result<ProtoOneof> {
+    let name = raw.name.clone();
+    let docs = raw.docs;
+    let variants = raw
+        .fields
+        .into_iter()
+        .map(|f| build_field(f, known_types))
+        .collect::<Result<Vec<_>>>()?;
+    ProtoOneofBuilder::default()
+        .name(Identifier::new(&name)?)
+        .variants(variants)
+        .docs(docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid oneof {}: {}", name, e)))
+}
 
-    /// Parse a service definition
-    fn parse_service(&self, name: &str, body: &str) -> Result<ProtoService> {
-        let identifier = Identifier::new(name)?;
-
-        let rpcs: Vec<ProtoRpc> = self
-            .rpc_re
-            .captures_iter(body)
-            .map(|caps| {
-                let rpc_name = caps.get(1).unwrap().as_str();
-                let client_streaming = caps.get(2).is_some();
-                let input_type = caps.get(3).unwrap().as_str();
-                let server_streaming = caps.get(4).is_some();
-                let output_type = caps.get(5).unwrap().as_str();
-
-                let input_proto_type = self.parse_proto_type(input_type, false)?;
-                let output_proto_type = self.parse_proto_type(output_type, false)?;
-
-                ProtoRpcBuilder::default()
-                    .name(Identifier::new(rpc_name)?)
-                    .input_type(input_proto_type)
-                    .output_type(output_proto_type)
-                    .client_streaming(client_streaming)
-                    .server_streaming(server_streaming)
-                    .build()
-                    .map_err(|e| {
-                        Error::validation_error(format!("Invalid RPC {}: {}", rpc_name, e))
-                    })
-            })
-            .collect::<Result<Vec<_>>>()?;
+fn build_message(raw: RawMessage, known_types: &HashMap<String, TypeKind>) -> Result<ProtoMessage> {
+    let name = raw.name.clone();
+    let docs = raw.docs.clone();
+    let fields = raw
+        .fields
+        .into_iter()
+        .map(|f| build_field(f, known_types))
+        .collect::<Result<Vec<_>>>()?;
+    let nested_messages = raw
+        .nested_messages
+        .into_iter()
+        .map(|m| {
+            let nested_name = m.name.clone();
+            Ok((nested_name, build_message(m, known_types)?))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    let oneofs = raw
+        .oneofs
+        .into_iter()
+        .map(|o| build_oneof(o, known_types))
+        .collect::<Result<Vec<_>>>()?;
 
-        ProtoServiceBuilder::default()
-            .name(identifier)
-            .rpcs(rpcs)
-            .build()
-            .map_err(|e| Error::validation_error(format!("Invalid service {}: {}", name, e)))
-    }
+    ProtoMessageBuilder::default()
+        .name(Identifier::new(&name)?)
+        .fields(fields)
+        .nested_messages(nested_messages)
+        .oneofs(oneofs)
+        .docs(docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid message {}: {}", name, e)))
 }
 
-impl Default for ProtoParser {
-    fn default() -> Self {
-        Self::new()
+fn build_enum(raw: RawEnum) -> Result<ProtoEnum> {
+    let name = raw.name.clone();
+    let docs = raw.docs;
+    let value_docs: HashMap<String, String> = raw
+        .values
+        .iter()
+        .filter_map(|(name, _, doc)| doc.clone().map(|doc| (name.clone(), doc)))
+        .collect();
+    let values: HashMap<String, i32> = raw.values.into_iter().map(|(name, value, _)| (name, value)).collect();
+    ProtoEnumBuilder::default()
+        .name(Identifier::new(&name)?)
+        .values(values)
+        .value_docs(value_docs)
+        .docs(docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid enum {}: {}", name, e)))
+}
+
+fn build_rpc(raw: RawRpc) -> Result<ProtoRpc> {
+    let name = raw.name.clone();
+    let input_type = parse_proto_type(&raw.input_type, false)?;
+    let output_type = parse_proto_type(&raw.output_type, false)?;
+    ProtoRpcBuilder::default()
+        .name(Identifier::new(&name)?)
+        .input_type(input_type)
+        .output_type(output_type)
+        .client_streaming(raw.client_streaming)
+        .server_streaming(raw.server_streaming)
+        .docs(raw.docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid RPC {}: {}", name, e)))
+}
+
+fn build_service(raw: RawService) -> Result<ProtoService> {
+    let name = raw.name.clone();
+    let docs = raw.docs;
+    let rpcs = raw.rpcs.into_iter().map(build_rpc).collect::<Result<Vec<_>>>()?;
+    ProtoServiceBuilder::default()
+        .name(Identifier::new(&name)?)
+        .rpcs(rpcs)
+        .docs(docs)
+        .build()
+        .map_err(|e| Error::validation_error(format!("Invalid service {}: {}", name, e)))
+}
+
+// ---------------------------------------------------------------------
+// Error reporting
+// ---------------------------------------------------------------------
+
+/// Convert a nom parse failure's remaining-input pointer into a 1-based
+/// line/column, for an `Error::parse_error` message a human can act on.
+fn line_col(content: &str, remaining: &str) -> (usize, usize) {
+    let offset = content.len() - remaining.len();
+    let mut line = 1;
+    let mut col = 1;
+    for ch in content[..offset.min(content.len())].chars() {
+        if ch == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
     }
+    (line, col)
+}
+
+fn nom_error_to_parse_error(content: &str, proto_path: &Path, err: nom::Err<nom::error::Error<&str>>) -> Error {
+    let remaining = match &err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.input,
+        nom::Err::Incomplete(_) => "",
+    };
+    let (line, col) = line_col(content, remaining);
+    let snippet: String = remaining.chars().take(40).collect();
+    Error::parse_error(proto_path, format!("Unexpected input at line {}, column {}: {:?}", line, col, snippet))
 }
 
 #[cfg(test)]
@@ -378,15 +1019,15 @@ mod tests {
         let proto_content = r#"
             syntax = "proto3";
             package helloworld;
-            
+
             message HelloRequest {
                 string name = 1;
             }
-            
+
             message HelloReply {
                 string message = 1;
             }
-            
+
             service Greeter {
                 rpc SayHello (HelloRequest) returns (HelloReply);
             }
@@ -405,4 +1046,174 @@ mod tests {
         assert_eq!(proto.services().len(), 1);
         assert_eq!(proto.services()[0].name().as_str(), "Greeter");
     }
+
+    #[test]
+    fn test_parse_collects_import_paths() {
+        let proto_content = r#"
+            syntax = "proto3";
+            package quote;
+
+            import "common/ticker.proto";
+            import public "common/currency.proto";
+
+            message Quote {
+                string symbol = 1;
+            }
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "{}", proto_content).unwrap();
+
+        let parser = ProtoParser::new();
+        let proto = parser.parse_file(temp_file.path()).unwrap();
+
+        assert_eq!(
+            proto.imports(),
+            &["common/ticker.proto".to_string(), "common/currency.proto".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_handles_comments_and_field_options() {
+        let proto_content = r#"
+            syntax = "proto3"; // trailing line comment
+            package demo;
+
+            /* a block comment
+               spanning lines */
+            message Order {
+                // a "quoted" string inside a comment must not confuse the parser
+                string id = 1 [deprecated = true];
+                reserved 2, 3;
+                repeated string tags = 4;
+            }
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "{}", proto_content).unwrap();
+
+        let parser = ProtoParser::new();
+        let proto = parser.parse_file(temp_file.path()).unwrap();
+
+        let order = proto.messages().get("Order").unwrap();
+        assert_eq!(order.fields().len(), 2);
+        assert_eq!(order.fields()[0].name().as_str(), "id");
+        assert_eq!(order.fields()[1].name().as_str(), "tags");
+    }
+
+    #[test]
+    fn test_parse_map_and_oneof_fields() {
+        let proto_content = r#"
+            syntax = "proto3";
+            package demo;
+
+            message Quote {
+                map<string, int32> counts = 1;
+
+                oneof payload {
+                    string text = 2;
+                    int32 code = 3;
+                }
+            }
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "{}", proto_content).unwrap();
+
+        let parser = ProtoParser::new();
+        let proto = parser.parse_file(temp_file.path()).unwrap();
+
+        let quote = proto.messages().get("Quote").unwrap();
+        assert_eq!(quote.fields().len(), 1);
+        assert!(matches!(quote.fields()[0].field_type(), ProtoType::Map { .. }));
+
+        assert_eq!(quote.oneofs().len(), 1);
+        let payload = &quote.oneofs()[0];
+        assert_eq!(payload.name().as_str(), "payload");
+        assert_eq!(payload.variants().len(), 2);
+        assert_eq!(payload.variants()[0].name().as_str(), "text");
+        assert_eq!(payload.variants()[1].name().as_str(), "code");
+    }
+
+    #[test]
+    fn test_parse_classifies_enum_typed_field_within_file() {
+        let proto_content = r#"
+            syntax = "proto3";
+            package demo;
+
+            enum Status {
+                UNKNOWN = 0;
+                ACTIVE = 1;
+            }
+
+            message Order {
+                Status status = 1;
+                Customer customer = 2;
+            }
+
+            message Customer {
+                string name = 1;
+            }
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "{}", proto_content).unwrap();
+
+        let parser = ProtoParser::new();
+        let proto = parser.parse_file(temp_file.path()).unwrap();
+
+        let order = proto.messages().get("Order").unwrap();
+        assert!(matches!(
+            order.fields()[0].field_type(),
+            ProtoType::Enum { name, .. } if name == "Status"
+        ));
+        assert!(matches!(
+            order.fields()[1].field_type(),
+            ProtoType::Message { name, .. } if name == "Customer"
+        ));
+    }
+
+    #[test]
+    fn test_parse_preserves_doc_comments() {
+        let proto_content = r#"
+            syntax = "proto3";
+            package demo;
+
+            // A customer order.
+            message Order {
+                string id = 1; // unique order id
+            }
+
+            enum Status {
+                UNKNOWN = 0;
+                SHIPPED = 1; // order has left the warehouse
+            }
+        "#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "{}", proto_content).unwrap();
+
+        let parser = ProtoParser::new();
+        let proto = parser.parse_file(temp_file.path()).unwrap();
+
+        let order = proto.messages().get("Order").unwrap();
+        assert_eq!(order.docs(), Some("A customer order."));
+        assert_eq!(order.fields()[0].docs(), Some("unique order id"));
+
+        let status = proto.enums().get("Status").unwrap();
+        assert_eq!(status.value_docs().get("SHIPPED").map(String::as_str), Some("order has left the warehouse"));
+    }
+
+    #[test]
+    fn test_parse_reports_line_and_column_on_syntax_error() {
+        let proto_content = "syntax = \"proto3\";\nmessage Foo {\n  string name = ;\n}\n";
+
+        let parser = ProtoParser::new();
+        let err = parser
+            .parse_content(proto_content, Path::new("broken.proto"))
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("line 3"), "expected a line-3 error, got: {message}");
+    }
 }
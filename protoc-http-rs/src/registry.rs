@@ -0,0 +1,225 @@
+use crate::error::{Error, Result};
+use crate::imports;
+use crate::parser::ProtoParser;
+use crate::types::{PackageName, ProtoEnum, ProtoFile, ProtoMessage, ProtoType};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A type found while indexing a [`ProtoRegistry`], identified by which
+/// loaded file defines it and its name within that file.
+enum SymbolLocation {
+    Message(usize, String),
+    Enum(usize, String),
+}
+
+/// A resolved reference into a loaded proto file: either a message or an
+/// enum definition.
+#[derive(Debug, Clone, Copy)]
+pub enum Symbol<'a> {
+    Message(&'a ProtoMessage),
+    Enum(&'a ProtoEnum),
+}
+
+/// A cross-referenced set of `.proto` files: every file reachable from a
+/// set of entry points, following `import` statements transitively (via
+/// [`crate::imports::resolve_closure`]), parsed once and indexed by
+/// fully-qualified type name (`package.TypeName`, or just `TypeName` for
+/// a file with no package).
+///
+/// Every file in the transitive closure contributes to one flat symbol
+/// table. This mirrors `import public`'s re-export behavior (a type
+/// becomes visible to anything that transitively imports its file) at the
+/// cost of not enforcing protoc's stricter private-import visibility
+/// rules, which is a lint in protoc itself rather than a hard error.
+pub struct ProtoRegistry {
+    files: Vec<ProtoFile>,
+    symbols: HashMap<String, Vec<SymbolLocation>>,
+}
+
+impl ProtoRegistry {
+    /// Follow `import` statements transitively from `entry_files` (using
+    /// `search_dirs` as the protoc-style `-I` list), parse every file in
+    /// the closure exactly once, and index their top-level messages and
+    /// enums by fully-qualified name.
+    pub fn load(entry_files: &[PathBuf], search_dirs: &[PathBuf], parser: &ProtoParser) -> Result<Self> {
+        let closure = imports::resolve_closure(entry_files, search_dirs, parser)?;
+        let files = closure.iter().map(|path| parser.parse_file(path)).collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_files(files))
+    }
+
+    /// Index already-parsed files into a registry.
+    fn from_files(files: Vec<ProtoFile>) -> Self {
+        let mut symbols: HashMap<String, Vec<SymbolLocation>> = HashMap::new();
+        for (file_index, file) in files.iter().enumerate() {
+            for name in file.messages().keys() {
+                symbols
+                    .entry(qualify(file, name))
+                    .or_default()
+                    .push(SymbolLocation::Message(file_index, name.clone()));
+            }
+            for name in file.enums().keys() {
+                symbols
+                    .entry(qualify(file, name))
+                    .or_default()
+                    .push(SymbolLocation::Enum(file_index, name.clone()));
+            }
+        }
+
+        Self { files, symbols }
+    }
+
+    /// The loaded files, in dependency-before-dependent order (matching
+    /// [`imports::resolve_closure`]'s ordering).
+    pub fn files(&self) -> &[ProtoFile] {
+        &self.files
+    }
+
+    /// Resolve a fully-qualified name (e.g. `common.Ticker`, or `Ticker`
+    /// for a type in a package-less file) to the message or enum that
+    /// defines it.
+    pub fn resolve(&self, fqname: &str) -> Result<Symbol<'_>> {
+        self.resolve_with_file(fqname).map(|(_, symbol)| symbol)
+    }
+
+    /// Like [`ProtoRegistry::resolve`], but also returns the file that
+    /// defines the symbol - needed to keep resolving that symbol's own
+    /// field types against *its* package once recursing into it (e.g. to
+    /// scaffold a nested message's fields; see `crate::scaffold`).
+    pub fn resolve_with_file(&self, fqname: &str) -> Result<(&ProtoFile, Symbol<'_>)> {
+        match self.symbols.get(fqname) {
+            None | Some([]) => Err(Error::unresolved_type(fqname)),
+            Some(locations) if locations.len() > 1 => Err(Error::ambiguous_type(fqname, locations.len())),
+            Some(locations) => {
+                let (file_idx, symbol) = match &locations[0] {
+                    SymbolLocation::Message(idx, name) => (*idx, Symbol::Message(&self.files[*idx].messages()[name])),
+                    SymbolLocation::Enum(idx, name) => (*idx, Symbol::Enum(&self.files[*idx].enums()[name])),
+                };
+                Ok((&self.files[file_idx], symbol))
+            }
+        }
+    }
+
+    /// Resolve every `ProtoType::Message`/`ProtoType::Enum` reference
+    /// reachable from any loaded file's messages, erroring on the first
+    /// unresolved or ambiguous name. The parser doesn't yet distinguish a
+    /// custom-type reference that's really an enum from one that's really
+    /// a message (see `parse_proto_type`), so a `ProtoType::Message` is
+    /// accepted as resolved against either kind of symbol.
+    pub fn validate_references(&self) -> Result<()> {
+        for file in &self.files {
+            for message in file.messages().values() {
+                self.validate_message(file, message)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_message(&self, file: &ProtoFile, message: &ProtoMessage) -> Result<()> {
+        for field in message.fields() {
+            self.validate_type(file, field.field_type())?;
+        }
+        for oneof in message.oneofs() {
+            for field in oneof.variants() {
+                self.validate_type(file, field.field_type())?;
+            }
+        }
+        for nested in message.nested_messages().values() {
+            self.validate_message(file, nested)?;
+        }
+        Ok(())
+    }
+
+    fn validate_type(&self, file: &ProtoFile, proto_type: &ProtoType) -> Result<()> {
+        match proto_type {
+            ProtoType::Scalar(_) => Ok(()),
+            ProtoType::Message { name, package } | ProtoType::Enum { name, package } => {
+                self.resolve(&fqname_for(file, name, package.as_ref())).map(|_| ())
+            }
+            ProtoType::Repeated(inner) => self.validate_type(file, inner),
+            ProtoType::Map { value, .. } => self.validate_type(file, value),
+        }
+    }
+}
+
+/// The fully-qualified name under which `file`'s own top-level `name`
+/// definition is indexed.
+fn qualify(file: &ProtoFile, name: &str) -> String {
+    match file.package() {
+        Some(pkg) => format!("{}.{}", pkg.as_str(), name),
+        None => name.to_string(),
+    }
+}
+
+/// The fully-qualified name a reference to `name` resolves to: `package`
+/// when the reference was already qualified, otherwise `file`'s own
+/// package (proto3's same-package lookup rule).
+pub(crate) fn fqname_for(file: &ProtoFile, name: &str, package: Option<&PackageName>) -> String {
+    match package.or(file.package()) {
+        Some(pkg) => format!("{}.{}", pkg.as_str(), name),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_finds_message_across_import() {
+        let common_dir = TempDir::new().unwrap();
+        fs::write(
+            common_dir.path().join("ticker.proto"),
+            r#"syntax = "proto3"; package common; message Ticker { string symbol = 1; }"#,
+        )
+        .unwrap();
+
+        let main_dir = TempDir::new().unwrap();
+        let main_file = main_dir.path().join("quote.proto");
+        fs::write(
+            &main_file,
+            r#"syntax = "proto3"; package quote; import "ticker.proto"; message Quote { common.Ticker ticker = 1; }"#,
+        )
+        .unwrap();
+
+        let parser = ProtoParser::new();
+        let registry =
+            ProtoRegistry::load(&[main_file], &[common_dir.path().to_path_buf()], &parser).unwrap();
+
+        registry.validate_references().unwrap();
+        assert!(matches!(registry.resolve("common.Ticker").unwrap(), Symbol::Message(_)));
+    }
+
+    #[test]
+    fn test_validate_references_reports_unresolved_type() {
+        let main_dir = TempDir::new().unwrap();
+        let main_file = main_dir.path().join("quote.proto");
+        fs::write(
+            &main_file,
+            r#"syntax = "proto3"; package quote; message Quote { common.Ticker ticker = 1; }"#,
+        )
+        .unwrap();
+
+        let parser = ProtoParser::new();
+        let registry = ProtoRegistry::load(&[main_file], &[], &parser).unwrap();
+
+        let err = registry.validate_references().unwrap_err();
+        assert!(err.to_string().contains("common.Ticker"));
+    }
+
+    #[test]
+    fn test_resolve_reports_ambiguous_type() {
+        let dir = TempDir::new().unwrap();
+        let file_a = dir.path().join("a.proto");
+        let file_b = dir.path().join("b.proto");
+        fs::write(&file_a, r#"syntax = "proto3"; package dup; message Thing { string id = 1; }"#).unwrap();
+        fs::write(&file_b, r#"syntax = "proto3"; package dup; message Thing { int32 id = 1; }"#).unwrap();
+
+        let parser = ProtoParser::new();
+        let registry = ProtoRegistry::load(&[file_a, file_b], &[], &parser).unwrap();
+
+        let err = registry.resolve("dup.Thing").unwrap_err();
+        assert!(err.to_string().contains("Ambiguous"));
+    }
+}
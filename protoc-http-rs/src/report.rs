@@ -0,0 +1,56 @@
+use crate::types::ProtoFile;
+use serde_json::{json, Value};
+
+/// Build the first event streamed by `--format json`: an overview of the
+/// whole run, following the `Plan`/`Result` event model Deno's test runner
+/// uses.
+pub fn plan_event(proto_files: usize, messages: usize, services: usize) -> Value {
+    json!({
+        "type": "plan",
+        "proto_files": proto_files,
+        "messages": messages,
+        "services": services,
+    })
+}
+
+/// Build one event per generated file, surfacing what was emitted (and
+/// which streaming RPCs were dropped) without requiring build tooling to
+/// scrape the generated text for absence of a method name.
+pub fn result_event(proto: &ProtoFile, output_path: &str, namespace: &str) -> Value {
+    let mut classes: Vec<&str> = proto.messages().keys().map(String::as_str).collect();
+    classes.sort_unstable();
+
+    let mut enums: Vec<&str> = proto.enums().keys().map(String::as_str).collect();
+    enums.sort_unstable();
+
+    let mut unary_rpcs = Vec::new();
+    let mut skipped_streaming_rpcs = Vec::new();
+    for service in proto.services() {
+        for rpc in service.rpcs() {
+            let qualified = format!("{}.{}", service.name().as_str(), rpc.name().as_str());
+            if rpc.is_unary() {
+                unary_rpcs.push(qualified);
+            } else {
+                skipped_streaming_rpcs.push(qualified);
+            }
+        }
+    }
+
+    json!({
+        "type": "result",
+        "output_path": output_path,
+        "namespace": namespace,
+        "classes": classes,
+        "enums": enums,
+        "unary_rpcs": unary_rpcs,
+        "skipped_streaming_rpcs": skipped_streaming_rpcs,
+    })
+}
+
+/// Emit an event as a single line of newline-delimited JSON.
+pub fn emit(event: &Value) {
+    match serde_json::to_string(event) {
+        Ok(line) => println!("{}", line),
+        Err(e) => eprintln!("Warning: failed to serialize generation report event: {}", e),
+    }
+}
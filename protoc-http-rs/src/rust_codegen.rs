@@ -0,0 +1,385 @@
+use crate::codegen::CodeGenerator;
+use crate::error::Result;
+use crate::target::{Rust as RustTarget, TargetLanguage};
+use crate::types::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Rust code generator emitting `serde`-annotated DTOs and a `reqwest`-based
+/// HTTP proxy client, mirroring [`crate::vb_codegen::VbNetGenerator`]'s
+/// request/response shape and routes so both backends talk to the same API.
+pub struct RustGenerator {
+    module_name: Option<String>,
+}
+
+impl RustGenerator {
+    /// Create a new Rust generator with an optional custom module name
+    /// (defaults to the proto package or file name, like the VB.NET
+    /// generator's namespace).
+    pub fn new(module_name: Option<String>) -> Self {
+        Self { module_name }
+    }
+
+    /// Generate the `use` declarations shared by every generated file.
+    fn generate_imports(&self) -> String {
+        "use serde::{Deserialize, Serialize};\nuse std::collections::HashMap;\n".to_string()
+    }
+
+    /// Generate the module open line, e.g. `pub mod helloworld {`.
+    fn generate_module_open(&self, proto: &ProtoFile) -> String {
+        format!("pub mod {} {{", self.module_name(proto))
+    }
+
+    fn module_name(&self, proto: &ProtoFile) -> String {
+        let default_name = proto.default_namespace_for(&RustTarget);
+        self.module_name.clone().unwrap_or(default_name)
+    }
+
+    /// Generate enum definitions, sorted by name for deterministic output
+    /// (proto enums are stored in a `HashMap`, so iteration order alone
+    /// isn't stable across runs).
+    fn generate_enums(&self, proto: &ProtoFile) -> String {
+        let mut enums: Vec<_> = proto.enums().values().collect();
+        enums.sort_by_key(|proto_enum| proto_enum.name().as_str());
+
+        enums
+            .into_iter()
+            .map(|proto_enum| self.generate_enum(proto_enum))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn generate_enum(&self, proto_enum: &ProtoEnum) -> String {
+        let enum_name = proto_enum.name();
+        let mut values: Vec<_> = proto_enum.values().iter().collect();
+        values.sort_by_key(|(_, value)| **value);
+
+        let values = values
+            .into_iter()
+            .map(|(key, value)| format!("        {} = {},", key, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]\n    pub enum {} {{\n{}\n    }}",
+            enum_name, values
+        )
+    }
+
+    /// Generate message definitions. Rust has no notion of a struct nested
+    /// inside another struct's body (unlike VB.NET's nested `Class`), so
+    /// nested messages are flattened into sibling struct definitions at the
+    /// same scope as their parent.
+    fn generate_messages(&self, proto: &ProtoFile) -> String {
+        let mut messages: Vec<_> = proto.messages().values().collect();
+        messages.sort_by_key(|message| message.name().as_str());
+
+        messages
+            .into_iter()
+            .map(|message| self.generate_message(message, proto))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn generate_message(&self, message: &ProtoMessage, proto: &ProtoFile) -> String {
+        let mut sections = Vec::new();
+
+        // Oneof discriminator enums are sibling items, declared ahead of the struct that uses them.
+        for oneof in message.oneofs() {
+            sections.push(self.generate_oneof_enum(oneof));
+        }
+
+        let mut lines = Vec::new();
+        lines.push("    #[derive(Debug, Clone, Serialize, Deserialize)]".to_string());
+        lines.push(format!("    pub struct {} {{", message.name()));
+
+        for field in message.fields() {
+            let field_type = field.field_type().to_target_type(&RustTarget, proto.package());
+            let json_name = to_camel_case(field.name().as_str());
+            let field_name = RustTarget.escape_identifier(&to_snake_case(field.name().as_str()));
+
+            lines.push(format!("        {}", RustTarget.property_attribute(&json_name)));
+            lines.push(format!("        pub {}: {},", field_name, field_type));
+        }
+
+        for oneof in message.oneofs() {
+            lines.extend(self.generate_oneof_fields(oneof, proto));
+        }
+
+        lines.push("    }".to_string());
+        sections.push(lines.join("\n"));
+
+        let mut nested_messages: Vec<_> = message.nested_messages().values().collect();
+        nested_messages.sort_by_key(|nested| nested.name().as_str());
+        for nested in nested_messages {
+            sections.push(self.generate_message(nested, proto));
+        }
+
+        sections.join("\n\n")
+    }
+
+    /// Generate the discriminator enum for a `oneof` group, e.g. `PayloadCase`.
+    fn generate_oneof_enum(&self, oneof: &ProtoOneof) -> String {
+        let enum_name = oneof.discriminator_enum_name();
+        let mut lines = Vec::new();
+
+        lines.push("    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]".to_string());
+        lines.push(format!("    pub enum {} {{", enum_name));
+        lines.push("        None,".to_string());
+        for variant in oneof.variants() {
+            lines.push(format!("        {},", to_pascal_case(variant.name().as_str())));
+        }
+        lines.push("    }".to_string());
+
+        lines.join("\n")
+    }
+
+    /// Generate the discriminator field plus one `Option<T>` field per
+    /// variant, mirroring the VB.NET generator's nullable sibling properties.
+    fn generate_oneof_fields(&self, oneof: &ProtoOneof, proto: &ProtoFile) -> Vec<String> {
+        let mut lines = Vec::new();
+        let enum_name = oneof.discriminator_enum_name();
+        let discriminator_field = to_snake_case(oneof.name().as_str());
+
+        lines.push(format!(
+            "        pub {}_case: {},",
+            discriminator_field, enum_name
+        ));
+
+        for variant in oneof.variants() {
+            let field_type = variant.field_type().to_target_type(&RustTarget, proto.package());
+            let json_name = to_camel_case(variant.name().as_str());
+            let field_name = RustTarget.escape_identifier(&to_snake_case(variant.name().as_str()));
+
+            lines.push(format!("        {}", RustTarget.property_attribute(&json_name)));
+            lines.push(format!("        pub {}: Option<{}>,", field_name, field_type));
+        }
+
+        lines
+    }
+
+    /// Generate service client definitions.
+    fn generate_services(&self, proto: &ProtoFile) -> String {
+        proto
+            .services()
+            .iter()
+            .map(|service| self.generate_service(service, proto))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn generate_service(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        let client_name = format!("{}Client", service.name());
+        let mut lines = Vec::new();
+
+        lines.extend([
+            format!("    pub struct {} {{", client_name),
+            "        http: reqwest::Client,".to_string(),
+            "        base_url: String,".to_string(),
+            "    }".to_string(),
+            "".to_string(),
+            format!("    impl {} {{", client_name),
+            "        pub fn new(http: reqwest::Client, base_url: impl Into<String>) -> Self {"
+                .to_string(),
+            "            Self {".to_string(),
+            "                http,".to_string(),
+            "                base_url: base_url.into().trim_end_matches('/').to_string(),"
+                .to_string(),
+            "            }".to_string(),
+            "        }".to_string(),
+            "".to_string(),
+        ]);
+
+        for rpc in service.unary_rpcs() {
+            lines.extend(self.generate_rpc_method(rpc, proto));
+            lines.push("".to_string());
+        }
+
+        lines.push("    }".to_string());
+        lines.join("\n")
+    }
+
+    fn generate_rpc_method(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = to_snake_case(rpc.name().as_str());
+        let input_type = rpc.input_type().to_target_type(&RustTarget, proto.package());
+        let output_type = rpc.output_type().to_target_type(&RustTarget, proto.package());
+        let relative_path = self.build_relative_path(rpc, proto);
+
+        vec![
+            format!(
+                "        pub async fn {}(&self, request: &{}) -> Result<{}, reqwest::Error> {{",
+                method_name, input_type, output_type
+            ),
+            format!(
+                "            let url = format!(\"{{}}{}\", self.base_url);",
+                relative_path
+            ),
+            "            self.http".to_string(),
+            "                .post(url)".to_string(),
+            "                .json(request)".to_string(),
+            "                .send()".to_string(),
+            "                .await?".to_string(),
+            "                .error_for_status()?".to_string(),
+            format!("                .json::<{}>()", output_type),
+            "                .await".to_string(),
+            "        }".to_string(),
+        ]
+    }
+
+    /// Build relative path string for an RPC method, matching
+    /// [`crate::vb_codegen::VbNetGenerator::build_relative_path`]'s
+    /// `/{file_stem}/{kebab-rpc}/{version}` convention so both backends
+    /// route to the same endpoints.
+    fn build_relative_path(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
+        let file_stem = Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        format!("/{}/{}/v{}", file_stem, rpc.url_name(), rpc.version())
+    }
+}
+
+impl CodeGenerator for RustGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let code = self.generate_code(proto)?;
+
+        fs::create_dir_all(output_dir)?;
+
+        let file_name = Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let output_file = output_dir.join(format!("{}.rs", file_name));
+
+        fs::write(&output_file, code)?;
+        Ok(output_file)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        let mut sections = Vec::new();
+
+        sections.push(self.generate_imports());
+        sections.push(self.generate_module_open(proto));
+
+        let enums = self.generate_enums(proto);
+        if !enums.is_empty() {
+            sections.push(enums);
+        }
+
+        let messages = self.generate_messages(proto);
+        if !messages.is_empty() {
+            sections.push(messages);
+        }
+
+        let services = self.generate_services(proto);
+        if !services.is_empty() {
+            sections.push(services);
+        }
+
+        sections.push("}".to_string());
+
+        Ok(sections.join("\n\n"))
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "rs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust HTTP proxy client and DTO generator (serde + reqwest)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_proto;
+
+    #[test]
+    fn test_rust_code_generation() {
+        let proto = create_test_proto("user_name");
+        let generator = RustGenerator::new(None);
+
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("pub mod helloworld {"));
+        assert!(code.contains("pub struct HelloRequest {"));
+        assert!(code.contains("pub struct HelloReply {"));
+        assert!(code.contains("pub struct GreeterClient {"));
+        assert!(code.contains("pub async fn say_hello"));
+
+        assert!(code.contains("#[serde(rename = \"userName\")]"));
+        assert!(code.contains("pub user_name: String,"));
+        assert!(code.contains("/helloworld/say-hello/v1"));
+        assert!(code.contains("use serde::{Deserialize, Serialize};"));
+    }
+
+    #[test]
+    fn test_custom_module_name() {
+        let proto = create_test_proto("user_name");
+        let generator = RustGenerator::new(Some("custom_mod".to_string()));
+
+        let code = generator.generate_code(&proto).unwrap();
+        assert!(code.contains("pub mod custom_mod {"));
+    }
+
+    #[test]
+    fn test_map_field_generates_hashmap() {
+        let map_type = ProtoType::map(ScalarType::String, ProtoType::Scalar(ScalarType::Int32)).unwrap();
+        assert_eq!(
+            map_type.to_target_type(&RustTarget, None),
+            "HashMap<String, i32>"
+        );
+    }
+
+    #[test]
+    fn test_oneof_generates_discriminator_and_optional_fields() {
+        let oneof = ProtoOneofBuilder::default()
+            .name(Identifier::new("payload").unwrap())
+            .variants(vec![
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("text").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(1)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("number").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::Int32))
+                    .field_number(2)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let message = ProtoMessageBuilder::default()
+            .name(Identifier::new("Event").unwrap())
+            .oneofs(vec![oneof])
+            .build()
+            .unwrap();
+
+        let proto = ProtoFileBuilder::default()
+            .file_name("event.proto".to_string())
+            .build()
+            .unwrap();
+
+        let generator = RustGenerator::new(None);
+        let code = generator.generate_message(&message, &proto);
+
+        assert!(code.contains("pub enum PayloadCase {"));
+        assert!(code.contains("        Text,"));
+        assert!(code.contains("        Number,"));
+        assert!(code.contains("pub payload_case: PayloadCase,"));
+        assert!(code.contains("#[serde(rename = \"text\")]"));
+        assert!(code.contains("pub text: Option<String>,"));
+    }
+
+    #[test]
+    fn test_rpc_method_name_is_snake_case() {
+        let proto = create_test_proto("user_name");
+        let generator = RustGenerator::new(None);
+        let code = generator.generate_code(&proto).unwrap();
+        assert!(code.contains("pub async fn say_hello(&self, request: &HelloRequest) -> Result<HelloReply, reqwest::Error> {"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::error::{Error, Result};
+use crate::registry::{self, ProtoRegistry, Symbol};
+use crate::types::{ProtoEnum, ProtoFile, ProtoType, ScalarType};
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+/// A ready-to-edit JSON payload template for one RPC method: a skeleton
+/// request with every field present and filled with a type-appropriate
+/// zero value, plus the streaming shape a caller needs to know about
+/// before sending it (one message vs. a stream).
+#[derive(Debug, Clone)]
+pub struct RpcScaffold {
+    pub request: Value,
+    pub client_streaming: bool,
+    pub server_streaming: bool,
+}
+
+/// Builds request scaffolds for RPCs defined anywhere in a [`ProtoRegistry`],
+/// recursing into message-typed fields (including across files, following
+/// the same package-qualification rules `ProtoRegistry::validate_references`
+/// uses) to produce a fully-populated skeleton rather than just the
+/// top-level shape.
+pub struct RequestScaffolder<'a> {
+    registry: &'a ProtoRegistry,
+}
+
+impl<'a> RequestScaffolder<'a> {
+    pub fn new(registry: &'a ProtoRegistry) -> Self {
+        Self { registry }
+    }
+
+    /// Scaffold the request payload for `service_name`'s `method_name` RPC.
+    pub fn scaffold_rpc(&self, service_name: &str, method_name: &str) -> Result<RpcScaffold> {
+        let (file, rpc) = self.find_rpc(service_name, method_name)?;
+        let request = self.scaffold_type(file, rpc.input_type(), &mut HashSet::new())?;
+        Ok(RpcScaffold {
+            request,
+            client_streaming: rpc.client_streaming(),
+            server_streaming: rpc.server_streaming(),
+        })
+    }
+
+    fn find_rpc(&self, service_name: &str, method_name: &str) -> Result<(&'a ProtoFile, &'a crate::types::ProtoRpc)> {
+        for file in self.registry.files() {
+            for service in file.services() {
+                if service.name().as_str() != service_name {
+                    continue;
+                }
+                return service
+                    .rpcs()
+                    .iter()
+                    .find(|rpc| rpc.name().as_str() == method_name)
+                    .map(|rpc| (file, rpc))
+                    .ok_or_else(|| Error::validation_error(format!("Unknown method {}.{}", service_name, method_name)));
+            }
+        }
+        Err(Error::validation_error(format!("Unknown service {}", service_name)))
+    }
+
+    /// Build a skeleton value for `proto_type`, resolved relative to
+    /// `file`'s package. `in_progress` tracks the fully-qualified names
+    /// currently being expanded on this recursion path, so a
+    /// self-referential or mutually-referential message stops one level
+    /// deep instead of recursing forever.
+    fn scaffold_type(&self, file: &ProtoFile, proto_type: &ProtoType, in_progress: &mut HashSet<String>) -> Result<Value> {
+        match proto_type {
+            ProtoType::Scalar(scalar) => Ok(scalar_zero_value(scalar)),
+            ProtoType::Repeated(_) => Ok(json!([])),
+            ProtoType::Map { .. } => Ok(json!({})),
+            ProtoType::Enum { name, package } => {
+                let fqname = registry::fqname_for(file, name, package.as_ref());
+                match self.registry.resolve(&fqname)? {
+                    Symbol::Enum(e) => Ok(json!(enum_zero_value(e))),
+                    Symbol::Message(_) => Err(Error::validation_error(format!("{} is a message, not an enum", fqname))),
+                }
+            }
+            ProtoType::Message { name, package } => {
+                let fqname = registry::fqname_for(file, name, package.as_ref());
+                if !in_progress.insert(fqname.clone()) {
+                    return Ok(json!(format!("<cycle: {}>", fqname)));
+                }
+
+                let (owning_file, symbol) = self.registry.resolve_with_file(&fqname)?;
+                let result = match symbol {
+                    Symbol::Message(msg) => {
+                        let mut fields = Map::new();
+                        for field in msg.fields() {
+                            let value = self.scaffold_type(owning_file, field.field_type(), in_progress)?;
+                            fields.insert(field.name().as_str().to_string(), value);
+                        }
+                        Ok(Value::Object(fields))
+                    }
+                    Symbol::Enum(_) => Err(Error::validation_error(format!("{} is an enum, not a message", fqname))),
+                };
+                in_progress.remove(&fqname);
+                result
+            }
+        }
+    }
+}
+
+fn scalar_zero_value(scalar: &ScalarType) -> Value {
+    match scalar {
+        ScalarType::String | ScalarType::Bytes => json!(""),
+        ScalarType::Bool => json!(false),
+        ScalarType::Float | ScalarType::Double => json!(0.0),
+        ScalarType::Int32
+        | ScalarType::Int64
+        | ScalarType::UInt32
+        | ScalarType::UInt64
+        | ScalarType::Sint32
+        | ScalarType::Sint64
+        | ScalarType::Fixed32
+        | ScalarType::Fixed64
+        | ScalarType::Sfixed32
+        | ScalarType::Sfixed64 => json!(0),
+    }
+}
+
+/// The name of the enum value that represents proto3's implicit default:
+/// the value numbered `0`, which every proto3 enum is required to declare.
+/// Falls back to the lowest-numbered value for a (non-conformant) enum
+/// that omits one.
+fn enum_zero_value(e: &ProtoEnum) -> String {
+    e.values()
+        .iter()
+        .find(|(_, value)| **value == 0)
+        .or_else(|| e.values().iter().min_by_key(|(_, value)| **value))
+        .map(|(name, _)| name.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ProtoParser;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scaffold_unary_rpc_fills_every_field_type() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("quote.proto");
+        fs::write(
+            &file,
+            r#"
+            syntax = "proto3";
+            package demo;
+
+            enum Status { UNKNOWN = 0; ACTIVE = 1; }
+
+            message GetQuoteRequest {
+                string symbol = 1;
+                repeated string tags = 2;
+                map<string, int32> filters = 3;
+                Status status = 4;
+            }
+
+            message GetQuoteResponse {
+                string symbol = 1;
+            }
+
+            service QuoteService {
+                rpc GetQuote (GetQuoteRequest) returns (GetQuoteResponse);
+            }
+            "#,
+        )
+        .unwrap();
+
+        let parser = ProtoParser::new();
+        let registry = ProtoRegistry::load(&[file], &[], &parser).unwrap();
+        let scaffolder = RequestScaffolder::new(&registry);
+
+        let scaffold = scaffolder.scaffold_rpc("QuoteService", "GetQuote").unwrap();
+        assert!(!scaffold.client_streaming);
+        assert!(!scaffold.server_streaming);
+        assert_eq!(scaffold.request["symbol"], json!(""));
+        assert_eq!(scaffold.request["tags"], json!([]));
+        assert_eq!(scaffold.request["filters"], json!({}));
+        assert_eq!(scaffold.request["status"], json!("UNKNOWN"));
+    }
+
+    #[test]
+    fn test_scaffold_recurses_into_nested_message_and_detects_cycles() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("tree.proto");
+        fs::write(
+            &file,
+            r#"
+            syntax = "proto3";
+            package demo;
+
+            message Node {
+                string label = 1;
+                Node child = 2;
+            }
+
+            message WrapNodeRequest {
+                Node root = 1;
+            }
+
+            message WrapNodeResponse {
+                bool ok = 1;
+            }
+
+            service TreeService {
+                rpc WrapNode (WrapNodeRequest) returns (WrapNodeResponse);
+            }
+            "#,
+        )
+        .unwrap();
+
+        let parser = ProtoParser::new();
+        let registry = ProtoRegistry::load(&[file], &[], &parser).unwrap();
+        let scaffolder = RequestScaffolder::new(&registry);
+
+        let scaffold = scaffolder.scaffold_rpc("TreeService", "WrapNode").unwrap();
+        let root = &scaffold.request["root"];
+        assert_eq!(root["label"], json!(""));
+        assert_eq!(root["child"]["label"], json!(""));
+        assert!(root["child"]["child"].as_str().unwrap().starts_with("<cycle:"));
+    }
+}
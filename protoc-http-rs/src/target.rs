@@ -0,0 +1,340 @@
+use crate::types::{to_pascal_case, to_snake_case, PackageName, ScalarType};
+use phf::phf_set;
+
+/// .NET reserved keywords that must be `@`-escaped to be used as C#
+/// identifiers. Not exhaustive of every contextual keyword, just the ones
+/// likely to collide with generated identifiers (proto field/message names).
+/// Source: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/
+static CSHARP_RESERVED_KEYWORDS: phf::Set<&'static str> = phf_set! {
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked",
+    "class", "const", "continue", "decimal", "default", "delegate", "do", "double", "else",
+    "enum", "event", "explicit", "extern", "false", "finally", "fixed", "float", "for",
+    "foreach", "goto", "if", "implicit", "in", "int", "interface", "internal", "is", "lock",
+    "long", "namespace", "new", "null", "object", "operator", "out", "override", "params",
+    "private", "protected", "public", "readonly", "ref", "return", "sbyte", "sealed", "short",
+    "sizeof", "stackalloc", "static", "string", "struct", "switch", "this", "throw", "true",
+    "try", "typeof", "uint", "ulong", "unchecked", "unsafe", "ushort", "using", "virtual",
+    "void", "volatile", "while"
+};
+
+/// Rust reserved and contextual keywords (2018+ edition) that must be raw-
+/// identifier-escaped (`r#ident`) to be used as identifiers. Source:
+/// https://doc.rust-lang.org/reference/keywords.html
+static RUST_RESERVED_KEYWORDS: phf::Set<&'static str> = phf_set! {
+    "as", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "async", "await", "abstract", "become", "box", "do",
+    "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try"
+};
+
+/// C# equivalents for `google.protobuf.*` well-known types, mirroring
+/// [`crate::types::WELL_KNOWN_VB_TYPES`] for the VB.NET backend.
+static CSHARP_WELL_KNOWN_TYPES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "google.protobuf.Timestamp" => "DateTime",
+    "google.protobuf.Duration" => "TimeSpan",
+    "google.protobuf.Int32Value" => "int?",
+    "google.protobuf.Int64Value" => "long?",
+    "google.protobuf.UInt32Value" => "uint?",
+    "google.protobuf.UInt64Value" => "ulong?",
+    "google.protobuf.FloatValue" => "float?",
+    "google.protobuf.DoubleValue" => "double?",
+    "google.protobuf.BoolValue" => "bool?",
+    "google.protobuf.StringValue" => "string",
+    "google.protobuf.BytesValue" => "byte[]",
+    "google.protobuf.Any" => "object",
+    "google.protobuf.Struct" => "object",
+    "google.protobuf.Value" => "object",
+};
+
+/// Rust equivalents for `google.protobuf.*` well-known types. `Timestamp`
+/// and `Duration` stay as `String` since they're serialized as RFC3339/JSON
+/// strings on the wire and this generator doesn't depend on `prost-types`.
+static RUST_WELL_KNOWN_TYPES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "google.protobuf.Timestamp" => "String",
+    "google.protobuf.Duration" => "String",
+    "google.protobuf.Int32Value" => "Option<i32>",
+    "google.protobuf.Int64Value" => "Option<i64>",
+    "google.protobuf.UInt32Value" => "Option<u32>",
+    "google.protobuf.UInt64Value" => "Option<u64>",
+    "google.protobuf.FloatValue" => "Option<f32>",
+    "google.protobuf.DoubleValue" => "Option<f64>",
+    "google.protobuf.BoolValue" => "Option<bool>",
+    "google.protobuf.StringValue" => "String",
+    "google.protobuf.BytesValue" => "Vec<u8>",
+    "google.protobuf.Any" => "serde_json::Value",
+    "google.protobuf.Struct" => "serde_json::Value",
+    "google.protobuf.Value" => "serde_json::Value",
+};
+
+/// Abstracts the target-language-specific concerns that were previously
+/// hard-wired to VB.NET, so a single `ProtoFile` can be rendered into any
+/// implementor's language: identifier casing/escaping, property attribute
+/// syntax, namespace/module mapping, and container/well-known type names.
+pub trait TargetLanguage {
+    /// Name of the idiomatic type for a proto scalar (e.g. `Integer`/`int`).
+    fn scalar_type_name(&self, scalar: &ScalarType) -> &'static str;
+
+    /// Wrap `inner`'s type name in this language's "list of T" container.
+    fn repeated_wrapper(&self, inner: &str) -> String;
+
+    /// Wrap a `map<K, V>` field in this language's associative-container
+    /// syntax (e.g. `Dictionary(Of K, V)`, `HashMap<K, V>`).
+    fn map_wrapper(&self, key: &str, value: &str) -> String;
+
+    /// Map a `google.protobuf.*` well-known type to this language's
+    /// idiomatic equivalent, given its fully-qualified name. Returns `None`
+    /// for anything that isn't a recognized well-known type.
+    fn well_known_type(&self, qualified_name: &str) -> Option<String>;
+
+    /// Convert a proto package name into this language's namespace/module form.
+    fn namespace_from_package(&self, package: &PackageName) -> String;
+
+    /// Escape `name` if it collides with a reserved keyword in this language.
+    fn escape_identifier(&self, name: &str) -> String;
+
+    /// The method-name suffix used for asynchronous calls in this language
+    /// (empty when `supports_async` is false, e.g. legacy synchronous modes).
+    fn method_suffix(&self, supports_async: bool) -> &'static str;
+
+    /// Render the attribute/annotation that maps a struct/class field to its
+    /// JSON wire name (e.g. `<JsonProperty("x")>`, `#[serde(rename = "x")]`).
+    fn property_attribute(&self, json_name: &str) -> String;
+}
+
+/// VB.NET target language implementation.
+pub struct VbNet;
+
+impl TargetLanguage for VbNet {
+    fn scalar_type_name(&self, scalar: &ScalarType) -> &'static str {
+        scalar.to_vb_type()
+    }
+
+    fn repeated_wrapper(&self, inner: &str) -> String {
+        format!("List(Of {})", inner)
+    }
+
+    fn map_wrapper(&self, key: &str, value: &str) -> String {
+        format!("Dictionary(Of {}, {})", key, value)
+    }
+
+    fn well_known_type(&self, qualified_name: &str) -> Option<String> {
+        crate::types::WELL_KNOWN_VB_TYPES.get(qualified_name).map(|s| s.to_string())
+    }
+
+    fn namespace_from_package(&self, package: &PackageName) -> String {
+        package.to_vb_namespace()
+    }
+
+    fn escape_identifier(&self, name: &str) -> String {
+        crate::types::escape_vb_identifier(name)
+    }
+
+    fn method_suffix(&self, supports_async: bool) -> &'static str {
+        if supports_async {
+            "Async"
+        } else {
+            ""
+        }
+    }
+
+    fn property_attribute(&self, json_name: &str) -> String {
+        format!("<JsonProperty(\"{}\")>", json_name)
+    }
+}
+
+/// C# target language implementation.
+pub struct CSharp;
+
+impl TargetLanguage for CSharp {
+    fn scalar_type_name(&self, scalar: &ScalarType) -> &'static str {
+        match scalar {
+            ScalarType::String => "string",
+            ScalarType::Int32 => "int",
+            ScalarType::Int64 => "long",
+            ScalarType::UInt32 => "uint",
+            ScalarType::UInt64 => "ulong",
+            ScalarType::Sint32 => "int",
+            ScalarType::Sint64 => "long",
+            ScalarType::Fixed32 => "uint",
+            ScalarType::Fixed64 => "ulong",
+            ScalarType::Sfixed32 => "int",
+            ScalarType::Sfixed64 => "long",
+            ScalarType::Bool => "bool",
+            ScalarType::Float => "float",
+            ScalarType::Double => "double",
+            ScalarType::Bytes => "byte[]",
+        }
+    }
+
+    fn repeated_wrapper(&self, inner: &str) -> String {
+        format!("List<{}>", inner)
+    }
+
+    fn map_wrapper(&self, key: &str, value: &str) -> String {
+        format!("Dictionary<{}, {}>", key, value)
+    }
+
+    fn well_known_type(&self, qualified_name: &str) -> Option<String> {
+        CSHARP_WELL_KNOWN_TYPES.get(qualified_name).map(|s| s.to_string())
+    }
+
+    fn namespace_from_package(&self, package: &PackageName) -> String {
+        // C# preserves the dotted namespace form, PascalCasing each segment.
+        package
+            .as_str()
+            .split('.')
+            .map(to_pascal_case)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn escape_identifier(&self, name: &str) -> String {
+        if CSHARP_RESERVED_KEYWORDS.contains(name) {
+            format!("@{}", name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn method_suffix(&self, supports_async: bool) -> &'static str {
+        if supports_async {
+            "Async"
+        } else {
+            ""
+        }
+    }
+
+    fn property_attribute(&self, json_name: &str) -> String {
+        format!("[JsonProperty(\"{}\")]", json_name)
+    }
+}
+
+/// Rust target language implementation, emitting `serde`-annotated structs
+/// with prost/tonic-compatible scalar types.
+pub struct Rust;
+
+impl TargetLanguage for Rust {
+    fn scalar_type_name(&self, scalar: &ScalarType) -> &'static str {
+        match scalar {
+            ScalarType::String => "String",
+            ScalarType::Int32 => "i32",
+            ScalarType::Int64 => "i64",
+            ScalarType::UInt32 => "u32",
+            ScalarType::UInt64 => "u64",
+            ScalarType::Sint32 => "i32",
+            ScalarType::Sint64 => "i64",
+            ScalarType::Fixed32 => "u32",
+            ScalarType::Fixed64 => "u64",
+            ScalarType::Sfixed32 => "i32",
+            ScalarType::Sfixed64 => "i64",
+            ScalarType::Bool => "bool",
+            ScalarType::Float => "f32",
+            ScalarType::Double => "f64",
+            ScalarType::Bytes => "Vec<u8>",
+        }
+    }
+
+    fn repeated_wrapper(&self, inner: &str) -> String {
+        format!("Vec<{}>", inner)
+    }
+
+    fn map_wrapper(&self, key: &str, value: &str) -> String {
+        format!("HashMap<{}, {}>", key, value)
+    }
+
+    fn well_known_type(&self, qualified_name: &str) -> Option<String> {
+        RUST_WELL_KNOWN_TYPES.get(qualified_name).map(|s| s.to_string())
+    }
+
+    fn namespace_from_package(&self, package: &PackageName) -> String {
+        // Flattened into a single snake_case module name, mirroring how
+        // VbNet/CSharp flatten a package into a single namespace string.
+        package
+            .as_str()
+            .split('.')
+            .map(to_snake_case)
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn escape_identifier(&self, name: &str) -> String {
+        if RUST_RESERVED_KEYWORDS.contains(name) {
+            format!("r#{}", name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn method_suffix(&self, _supports_async: bool) -> &'static str {
+        // Rust async methods keep their name as-is; callers use `.await`.
+        ""
+    }
+
+    fn property_attribute(&self, json_name: &str) -> String {
+        format!("#[serde(rename = \"{}\")]", json_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vb_net_target_language() {
+        let vb = VbNet;
+        assert_eq!(vb.scalar_type_name(&ScalarType::Int32), "Integer");
+        assert_eq!(vb.repeated_wrapper("Integer"), "List(Of Integer)");
+        assert_eq!(vb.escape_identifier("Error"), "[Error]");
+        assert_eq!(vb.escape_identifier("Name"), "Name");
+        assert_eq!(vb.method_suffix(true), "Async");
+        assert_eq!(vb.method_suffix(false), "");
+
+        let pkg = PackageName::new("com.example.api").unwrap();
+        assert_eq!(vb.namespace_from_package(&pkg), "ComExampleApi");
+        assert_eq!(vb.map_wrapper("String", "Integer"), "Dictionary(Of String, Integer)");
+        assert_eq!(vb.well_known_type("google.protobuf.Timestamp"), Some("Date".to_string()));
+        assert_eq!(vb.property_attribute("name"), "<JsonProperty(\"name\")>");
+    }
+
+    #[test]
+    fn test_csharp_target_language() {
+        let cs = CSharp;
+        assert_eq!(cs.scalar_type_name(&ScalarType::Int64), "long");
+        assert_eq!(cs.scalar_type_name(&ScalarType::Bytes), "byte[]");
+        assert_eq!(cs.repeated_wrapper("string"), "List<string>");
+        assert_eq!(cs.escape_identifier("class"), "@class");
+        assert_eq!(cs.escape_identifier("Name"), "Name");
+        assert_eq!(cs.method_suffix(true), "Async");
+
+        let pkg = PackageName::new("com.example.api").unwrap();
+        assert_eq!(cs.namespace_from_package(&pkg), "Com.Example.Api");
+        assert_eq!(cs.map_wrapper("string", "int"), "Dictionary<string, int>");
+        assert_eq!(cs.well_known_type("google.protobuf.Timestamp"), Some("DateTime".to_string()));
+        assert_eq!(cs.property_attribute("name"), "[JsonProperty(\"name\")]");
+    }
+
+    #[test]
+    fn test_rust_target_language() {
+        let rust = Rust;
+        assert_eq!(rust.scalar_type_name(&ScalarType::Int32), "i32");
+        assert_eq!(rust.scalar_type_name(&ScalarType::Bytes), "Vec<u8>");
+        assert_eq!(rust.repeated_wrapper("String"), "Vec<String>");
+        assert_eq!(rust.map_wrapper("String", "i32"), "HashMap<String, i32>");
+        assert_eq!(rust.escape_identifier("type"), "r#type");
+        assert_eq!(rust.escape_identifier("name"), "name");
+        assert_eq!(rust.method_suffix(true), "");
+        assert_eq!(rust.method_suffix(false), "");
+        assert_eq!(
+            rust.well_known_type("google.protobuf.Timestamp"),
+            Some("String".to_string())
+        );
+        assert_eq!(
+            rust.property_attribute("name"),
+            "#[serde(rename = \"name\")]"
+        );
+
+        let pkg = PackageName::new("com.example.api").unwrap();
+        assert_eq!(rust.namespace_from_package(&pkg), "com_example_api");
+    }
+}
@@ -0,0 +1,282 @@
+use crate::codegen::{CodeGenerator, TemplateEngine};
+use crate::error::{Error, Result};
+use crate::json_schema_codegen::JsonSchemaGenerator;
+use crate::types::{
+    to_camel_case, ProtoEnum, ProtoField, ProtoFile, ProtoMessage, ProtoRpc, ProtoService, ProtoType, ScalarType,
+};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tera::Tera;
+
+/// [`TemplateEngine`] backed by [`tera`], the Jinja2-alike templating crate.
+pub struct TeraTemplateEngine {
+    tera: Tera,
+}
+
+impl TeraTemplateEngine {
+    /// Load every `*.tera` file under `template_dir` (recursively) into one
+    /// [`Tera`] instance, so templates can `{% include %}` one another.
+    pub fn new(template_dir: &Path) -> Result<Self> {
+        let pattern = template_dir.join("**").join("*.tera");
+        let pattern = pattern
+            .to_str()
+            .ok_or_else(|| Error::validation_error(format!("template directory path is not valid UTF-8: {}", template_dir.display())))?;
+        let tera = Tera::new(pattern).map_err(|e| {
+            Error::codegen_error(format!("failed to load templates from {}: {}", template_dir.display(), e))
+        })?;
+        Ok(Self { tera })
+    }
+
+    fn template_names(&self) -> impl Iterator<Item = &str> {
+        self.tera.get_template_names()
+    }
+}
+
+impl TemplateEngine for TeraTemplateEngine {
+    fn render(&self, template: &str, context: &Value) -> Result<String> {
+        let ctx = tera::Context::from_serialize(context)
+            .map_err(|e| Error::codegen_error(format!("invalid template context: {}", e)))?;
+        self.tera
+            .render(template, &ctx)
+            .map_err(|e| Error::codegen_error(format!("failed to render template {}: {}", template, e)))
+    }
+}
+
+/// [`CodeGenerator`] that renders one output file per [`ProtoFile`] from a
+/// user-supplied directory of Tera templates (`--emit template
+/// --template-dir ./tpl`), so targeting a language this crate has no
+/// built-in backend for (TypeScript, Go, Python, ...) needs no new Rust
+/// code, just a template.
+///
+/// `template_dir` must contain exactly one entry template named
+/// `template.<ext>.tera` (e.g. `template.ts.tera`); `<ext>` becomes the
+/// generated files' extension. Any other `*.tera` files alongside it are
+/// loaded too and can be `{% include %}`d from the entry template.
+pub struct TemplateGenerator {
+    engine: TeraTemplateEngine,
+    entry_template: String,
+    extension: &'static str,
+}
+
+impl TemplateGenerator {
+    pub fn new(template_dir: &Path) -> Result<Self> {
+        let engine = TeraTemplateEngine::new(template_dir)?;
+
+        let entry_template = engine
+            .template_names()
+            .find(|name| name.starts_with("template.") && name.ends_with(".tera"))
+            .map(str::to_string)
+            .ok_or_else(|| {
+                Error::validation_error(format!(
+                    "no entry template in {}: expected a file named `template.<ext>.tera` (e.g. `template.ts.tera`)",
+                    template_dir.display()
+                ))
+            })?;
+
+        let extension = entry_template
+            .strip_prefix("template.")
+            .and_then(|rest| rest.strip_suffix(".tera"))
+            .filter(|ext| !ext.is_empty())
+            .unwrap_or("out");
+        // Leaked once per `TemplateGenerator` (one per process run) so
+        // `file_extension` can satisfy `CodeGenerator`'s `&'static str`
+        // return type without widening the trait for this one generator.
+        let extension: &'static str = Box::leak(extension.to_string().into_boxed_str());
+
+        Ok(Self { engine, entry_template, extension })
+    }
+}
+
+impl CodeGenerator for TemplateGenerator {
+    fn generate_to_file(&self, proto: &ProtoFile, output_dir: &Path) -> Result<PathBuf> {
+        let code = self.generate_code(proto)?;
+
+        fs::create_dir_all(output_dir)?;
+
+        let file_stem = Path::new(proto.file_name()).file_stem().unwrap_or_default().to_string_lossy();
+        let output_path = output_dir.join(format!("{}.{}", file_stem, self.extension));
+        fs::write(&output_path, &code)?;
+        Ok(output_path)
+    }
+
+    fn generate_code(&self, proto: &ProtoFile) -> Result<String> {
+        let context = build_context(proto);
+        self.engine.render(&self.entry_template, &context)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        self.extension
+    }
+
+    fn description(&self) -> &'static str {
+        "Renders a user-supplied Tera template directory (--template-dir) for target languages with no built-in generator"
+    }
+}
+
+/// Build the template context for a proto file: top-level `package`,
+/// `file_name`, and `namespace`, plus `messages` (nested messages flattened
+/// in with a dotted `qualified_name`, matching
+/// [`JsonSchemaGenerator`]'s `$defs` naming), `enums`, and `services`.
+fn build_context(proto: &ProtoFile) -> Value {
+    let mut messages = Vec::new();
+    for msg in proto.messages().values() {
+        flatten_message(msg, &[], &mut messages);
+    }
+
+    let mut enums: Vec<Value> = proto.enums().values().map(enum_context).collect();
+    enums.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    json!({
+        "package": proto.package().map(|pkg| pkg.as_str().to_string()),
+        "file_name": proto.file_name(),
+        "namespace": proto.default_namespace(),
+        "messages": messages,
+        "enums": enums,
+        "services": proto.services().iter().map(service_context).collect::<Vec<_>>(),
+    })
+}
+
+fn flatten_message(msg: &ProtoMessage, parent_path: &[String], out: &mut Vec<Value>) {
+    let mut path = parent_path.to_vec();
+    path.push(msg.name().as_str().to_string());
+
+    out.push(json!({
+        "name": msg.name().as_str(),
+        "qualified_name": path.join("."),
+        "fields": msg.fields().iter().map(field_context).collect::<Vec<_>>(),
+    }));
+
+    for nested in msg.nested_messages().values() {
+        flatten_message(nested, &path, out);
+    }
+}
+
+fn field_context(field: &ProtoField) -> Value {
+    let name = field.name().as_str();
+    json!({
+        "name": name,
+        "camel_name": to_camel_case(name),
+        "json_type": JsonSchemaGenerator::json_type_name(field.field_type()),
+        "is_repeated": matches!(field.field_type(), ProtoType::Repeated(_)),
+        "proto_type": proto_type_name(field.field_type()),
+        "field_number": field.field_number(),
+    })
+}
+
+fn enum_context(proto_enum: &ProtoEnum) -> Value {
+    let mut values: Vec<(&String, &i32)> = proto_enum.values().iter().collect();
+    values.sort_by_key(|(_, value)| **value);
+
+    json!({
+        "name": proto_enum.name().as_str(),
+        "values": values.into_iter().map(|(name, value)| json!({"name": name, "value": value})).collect::<Vec<_>>(),
+    })
+}
+
+fn service_context(service: &ProtoService) -> Value {
+    json!({
+        "name": service.name().as_str(),
+        "methods": service.rpcs().iter().map(method_context).collect::<Vec<_>>(),
+    })
+}
+
+fn method_context(rpc: &ProtoRpc) -> Value {
+    json!({
+        "name": rpc.name().as_str(),
+        "input_type": proto_type_name(rpc.input_type()),
+        "output_type": proto_type_name(rpc.output_type()),
+        "client_streaming": rpc.client_streaming(),
+        "server_streaming": rpc.server_streaming(),
+    })
+}
+
+/// The proto-source type name for a field: the bare element type for
+/// `repeated`/`map` fields (callers already get repeated-ness from
+/// `is_repeated`), or the message/enum name otherwise.
+fn proto_type_name(field_type: &ProtoType) -> String {
+    match field_type {
+        ProtoType::Scalar(scalar) => scalar_proto_keyword(scalar).to_string(),
+        ProtoType::Repeated(inner) => proto_type_name(inner),
+        ProtoType::Map { key, value } => format!("map<{}, {}>", proto_type_name(key), proto_type_name(value)),
+        ProtoType::Message { name, .. } | ProtoType::Enum { name, .. } => name.clone(),
+    }
+}
+
+fn scalar_proto_keyword(scalar: &ScalarType) -> &'static str {
+    match scalar {
+        ScalarType::String => "string",
+        ScalarType::Int32 => "int32",
+        ScalarType::Int64 => "int64",
+        ScalarType::UInt32 => "uint32",
+        ScalarType::UInt64 => "uint64",
+        ScalarType::Sint32 => "sint32",
+        ScalarType::Sint64 => "sint64",
+        ScalarType::Fixed32 => "fixed32",
+        ScalarType::Fixed64 => "fixed64",
+        ScalarType::Sfixed32 => "sfixed32",
+        ScalarType::Sfixed64 => "sfixed64",
+        ScalarType::Bool => "bool",
+        ScalarType::Float => "float",
+        ScalarType::Double => "double",
+        ScalarType::Bytes => "bytes",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Identifier, PackageName, ProtoFieldBuilder, ProtoFileBuilder, ProtoMessageBuilder};
+    use std::collections::HashMap;
+
+    fn test_proto() -> ProtoFile {
+        let message = ProtoMessageBuilder::default()
+            .name(Identifier::new("HelloRequest").unwrap())
+            .fields(vec![ProtoFieldBuilder::default()
+                .name(Identifier::new("user_name").unwrap())
+                .field_type(ProtoType::Scalar(ScalarType::String))
+                .field_number(1)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let mut messages = HashMap::new();
+        messages.insert("HelloRequest".to_string(), message);
+
+        ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .messages(messages)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_context_shape() {
+        let proto = test_proto();
+        let context = build_context(&proto);
+
+        assert_eq!(context["package"], "helloworld");
+        assert_eq!(context["file_name"], "helloworld.proto");
+
+        let messages = context["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0]["name"], "HelloRequest");
+        assert_eq!(messages[0]["qualified_name"], "HelloRequest");
+
+        let fields = messages[0]["fields"].as_array().unwrap();
+        assert_eq!(fields[0]["name"], "user_name");
+        assert_eq!(fields[0]["camel_name"], "userName");
+        assert_eq!(fields[0]["json_type"], "string");
+        assert_eq!(fields[0]["is_repeated"], false);
+        assert_eq!(fields[0]["proto_type"], "string");
+        assert_eq!(fields[0]["field_number"], 1);
+    }
+
+    #[test]
+    fn test_proto_type_name_unwraps_repeated() {
+        let repeated = ProtoType::Repeated(Box::new(ProtoType::Scalar(ScalarType::Int32)));
+        assert_eq!(proto_type_name(&repeated), "int32");
+    }
+}
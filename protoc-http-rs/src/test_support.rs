@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+use crate::types::*;
+
+/// Shared `helloworld.proto`-shaped fixture for the Rust and C# codegen unit
+/// tests: a `Greeter.SayHello(HelloRequest) -> HelloReply` RPC, with
+/// `HelloRequest`'s single field named by `request_field_name` so each
+/// generator's own tests can check their own naming-convention rewrite of it
+/// (`user_name` -> `userName` for Rust's serde rename, `name` -> `Name` for
+/// C#'s PascalCase properties) without sharing a field name neither actually
+/// exercises for the other.
+pub fn create_test_proto(request_field_name: &str) -> ProtoFile {
+    let hello_request = ProtoMessageBuilder::default()
+        .name(Identifier::new("HelloRequest").unwrap())
+        .fields(vec![ProtoFieldBuilder::default()
+            .name(Identifier::new(request_field_name).unwrap())
+            .field_type(ProtoType::Scalar(ScalarType::String))
+            .field_number(1)
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let hello_reply = ProtoMessageBuilder::default()
+        .name(Identifier::new("HelloReply").unwrap())
+        .fields(vec![ProtoFieldBuilder::default()
+            .name(Identifier::new("message").unwrap())
+            .field_type(ProtoType::Scalar(ScalarType::String))
+            .field_number(1)
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let say_hello_rpc = ProtoRpcBuilder::default()
+        .name(Identifier::new("SayHello").unwrap())
+        .input_type(ProtoType::Message {
+            name: "HelloRequest".to_string(),
+            package: None,
+        })
+        .output_type(ProtoType::Message {
+            name: "HelloReply".to_string(),
+            package: None,
+        })
+        .build()
+        .unwrap();
+
+    let greeter_service = ProtoServiceBuilder::default()
+        .name(Identifier::new("Greeter").unwrap())
+        .rpcs(vec![say_hello_rpc])
+        .build()
+        .unwrap();
+
+    let mut messages = std::collections::HashMap::new();
+    messages.insert("HelloRequest".to_string(), hello_request);
+    messages.insert("HelloReply".to_string(), hello_reply);
+
+    ProtoFileBuilder::default()
+        .file_name("helloworld.proto".to_string())
+        .package(Some(PackageName::new("helloworld").unwrap()))
+        .messages(messages)
+        .services(vec![greeter_service])
+        .build()
+        .unwrap()
+}
@@ -94,6 +94,353 @@ impl fmt::Display for CompatibilityMode {
     }
 }
 
+/// Wire protocol and routing convention used by generated service clients.
+/// Orthogonal to [`CompatibilityMode`], which only governs the .NET runtime
+/// surface (HttpClient vs. HttpWebRequest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireProtocol {
+    /// The ad-hoc `/{file_stem}/{kebab-rpc}/{version}` JSON-over-HTTP layout
+    /// this generator has always used.
+    Legacy,
+    /// [Twirp](https://twitchtv.github.io/twirp/): routes are
+    /// `/twirp/<package>.<Service>/<Method>`, and non-2xx responses carry a
+    /// `{"code", "msg", "meta"}` JSON error envelope instead of an arbitrary
+    /// body.
+    Twirp,
+    /// [Connect](https://connectrpc.com/)'s unary protocol: routes are
+    /// `/<package>.<Service>/<Method>`, requests carry a
+    /// `Connect-Protocol-Version: 1` header, and non-2xx responses carry a
+    /// `{"code", "message", "details"}` JSON error envelope using Connect's
+    /// canonical error codes.
+    Connect,
+}
+
+impl Default for WireProtocol {
+    fn default() -> Self {
+        WireProtocol::Legacy
+    }
+}
+
+impl FromStr for WireProtocol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "legacy" => Ok(WireProtocol::Legacy),
+            "twirp" => Ok(WireProtocol::Twirp),
+            "connect" => Ok(WireProtocol::Connect),
+            _ => Err(Error::validation_error(format!(
+                "Invalid wire protocol: {}. Supported protocols: legacy, twirp, connect",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for WireProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireProtocol::Legacy => write!(f, "legacy"),
+            WireProtocol::Twirp => write!(f, "twirp"),
+            WireProtocol::Connect => write!(f, "connect"),
+        }
+    }
+}
+
+/// Wire payload encoding used by generated service clients. Orthogonal to
+/// [`WireProtocol`], which only governs routing and error envelopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationFormat {
+    /// Serialize requests/responses as JSON via `JsonConvert`, the
+    /// generator's original behavior.
+    Json,
+    /// Serialize requests/responses as binary protobuf via protobuf-net's
+    /// `ProtoBuf.Serializer`, using each field's proto field number
+    /// (`<ProtoMember(N)>`) instead of JSON property names.
+    Protobuf,
+}
+
+impl Default for SerializationFormat {
+    fn default() -> Self {
+        SerializationFormat::Json
+    }
+}
+
+impl FromStr for SerializationFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(SerializationFormat::Json),
+            "protobuf" => Ok(SerializationFormat::Protobuf),
+            _ => Err(Error::validation_error(format!(
+                "Invalid serialization format: {}. Supported formats: json, protobuf",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for SerializationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerializationFormat::Json => write!(f, "json"),
+            SerializationFormat::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+/// Which side(s) of the HTTP/JSON gateway `generate_code` emits. Orthogonal
+/// to [`CompatibilityMode`]/[`WireProtocol`]/[`SerializationFormat`], which
+/// only shape the client side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationTarget {
+    /// Emit only the `FooClient` HTTP client (the generator's original behavior).
+    Client,
+    /// Emit only the `FooControllerBase` abstract ASP.NET controller.
+    Server,
+    /// Emit both the client and the server controller base.
+    Both,
+}
+
+impl GenerationTarget {
+    /// Whether this target includes the `FooClient` HTTP client.
+    pub fn includes_client(self) -> bool {
+        matches!(self, GenerationTarget::Client | GenerationTarget::Both)
+    }
+
+    /// Whether this target includes the `FooControllerBase` server stub.
+    pub fn includes_server(self) -> bool {
+        matches!(self, GenerationTarget::Server | GenerationTarget::Both)
+    }
+}
+
+impl Default for GenerationTarget {
+    fn default() -> Self {
+        GenerationTarget::Client
+    }
+}
+
+impl FromStr for GenerationTarget {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "client" => Ok(GenerationTarget::Client),
+            "server" => Ok(GenerationTarget::Server),
+            "both" => Ok(GenerationTarget::Both),
+            _ => Err(Error::validation_error(format!(
+                "Invalid generation target: {}. Supported targets: client, server, both",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for GenerationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerationTarget::Client => write!(f, "client"),
+            GenerationTarget::Server => write!(f, "server"),
+            GenerationTarget::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// How a generated client authenticates each outgoing call. Mirrors
+/// standard gRPC call-credentials (a per-call token provider) and
+/// metadata-based auth (a static header), applied at the HTTP/JSON layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialMode {
+    /// No authentication; the generator's original behavior.
+    None,
+    /// Await a `tokenProvider` delegate before each call and send its
+    /// result as `Authorization: Bearer <token>`.
+    BearerToken,
+    /// Send a fixed value as a static API-key header on every call.
+    ApiKey,
+}
+
+impl Default for CredentialMode {
+    fn default() -> Self {
+        CredentialMode::None
+    }
+}
+
+impl FromStr for CredentialMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(CredentialMode::None),
+            "bearer-token" => Ok(CredentialMode::BearerToken),
+            "api-key" => Ok(CredentialMode::ApiKey),
+            _ => Err(Error::validation_error(format!(
+                "Invalid credential mode: {}. Supported modes: none, bearer-token, api-key",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for CredentialMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialMode::None => write!(f, "none"),
+            CredentialMode::BearerToken => write!(f, "bearer-token"),
+            CredentialMode::ApiKey => write!(f, "api-key"),
+        }
+    }
+}
+
+/// Casing policy for an emitted `<JsonProperty("...")>` wire name, rendered
+/// from the original proto field name. Defaults to [`PropertyCasing::CamelCase`],
+/// matching the generator's original (pre-[`NamingConfig`]) behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyCasing {
+    CamelCase,
+    PascalCase,
+    SnakeCase,
+    /// Emit the proto field name verbatim, with no case conversion.
+    AsIs,
+}
+
+impl PropertyCasing {
+    /// Render `field_name` (the original proto field/oneof-variant name)
+    /// under this casing policy.
+    pub fn apply(&self, field_name: &str) -> String {
+        match self {
+            PropertyCasing::CamelCase => to_camel_case(field_name),
+            PropertyCasing::PascalCase => to_pascal_case(field_name),
+            PropertyCasing::SnakeCase => to_snake_case(field_name),
+            PropertyCasing::AsIs => field_name.to_string(),
+        }
+    }
+}
+
+impl Default for PropertyCasing {
+    fn default() -> Self {
+        PropertyCasing::CamelCase
+    }
+}
+
+impl FromStr for PropertyCasing {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "camel-case" => Ok(PropertyCasing::CamelCase),
+            "pascal-case" => Ok(PropertyCasing::PascalCase),
+            "snake-case" => Ok(PropertyCasing::SnakeCase),
+            "as-is" => Ok(PropertyCasing::AsIs),
+            _ => Err(Error::validation_error(format!(
+                "Invalid property casing: {}. Supported casings: camel-case, pascal-case, snake-case, as-is",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for PropertyCasing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyCasing::CamelCase => write!(f, "camel-case"),
+            PropertyCasing::PascalCase => write!(f, "pascal-case"),
+            PropertyCasing::SnakeCase => write!(f, "snake-case"),
+            PropertyCasing::AsIs => write!(f, "as-is"),
+        }
+    }
+}
+
+/// Overrides for generated names, letting teams integrating generated code
+/// match in-house naming conventions instead of accepting the generator's
+/// defaults (distinct server-side vs. client-side names, existing C#/VB
+/// style guides, etc). Consulted at every class/namespace/JSON-property
+/// emission point in [`crate::vb_codegen::VbNetGenerator`].
+#[derive(Debug, Clone, Default)]
+pub struct NamingConfig {
+    /// Overrides the generated type/method name for a proto message,
+    /// service, or RPC, keyed by its original proto identifier (e.g.
+    /// `"HelloRequest"`, `"Greeter"`, `"SayHello"`).
+    type_overrides: HashMap<String, String>,
+    /// Casing policy for emitted `<JsonProperty("...")>` wire names.
+    property_casing: PropertyCasing,
+    /// Overrides the generated namespace for a proto package, keyed by the
+    /// package's dotted name (e.g. `"helloworld.v1"`). Falls back to the
+    /// generator's usual default (the package, PascalCased) when absent.
+    namespace_overrides: HashMap<String, String>,
+}
+
+impl NamingConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a type/method-name override for the proto identifier `original`.
+    pub fn with_type_override(mut self, original: impl Into<String>, renamed: impl Into<String>) -> Self {
+        self.type_overrides.insert(original.into(), renamed.into());
+        self
+    }
+
+    /// Set the casing policy for emitted JSON property names.
+    pub fn with_property_casing(mut self, casing: PropertyCasing) -> Self {
+        self.property_casing = casing;
+        self
+    }
+
+    /// Add a namespace override for the proto package `package`.
+    pub fn with_namespace_override(mut self, package: impl Into<String>, namespace: impl Into<String>) -> Self {
+        self.namespace_overrides.insert(package.into(), namespace.into());
+        self
+    }
+
+    /// The generated name for the proto identifier `original` (a message,
+    /// service, or RPC name), honoring [`Self::type_overrides`]. Falls back
+    /// to `original` unchanged when no override is configured.
+    pub fn resolve_type_name(&self, original: &str) -> String {
+        self.type_overrides.get(original).cloned().unwrap_or_else(|| original.to_string())
+    }
+
+    /// The generated namespace for `package` (the proto package's dotted
+    /// name), honoring [`Self::namespace_overrides`]; falls back to
+    /// `default_ns` when no override is configured or `package` is `None`.
+    pub fn resolve_namespace(&self, package: Option<&str>, default_ns: String) -> String {
+        package.and_then(|pkg| self.namespace_overrides.get(pkg)).cloned().unwrap_or(default_ns)
+    }
+
+    pub fn property_casing(&self) -> PropertyCasing {
+        self.property_casing
+    }
+
+    /// Parse a naming config from JSON, mirroring
+    /// [`crate::json_schema_codegen`]'s untyped-`Value` style rather than a
+    /// typed `Deserialize` impl: `{"type_overrides": {"HelloRequest":
+    /// "HelloRequestDto"}, "property_casing": "snake-case",
+    /// "namespace_overrides": {"helloworld.v1": "Acme.Helloworld"}}`. Every
+    /// key is optional.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(overrides) = value.get("type_overrides").and_then(|v| v.as_object()) {
+            for (name, renamed) in overrides {
+                if let Some(renamed) = renamed.as_str() {
+                    config.type_overrides.insert(name.clone(), renamed.to_string());
+                }
+            }
+        }
+        if let Some(casing) = value.get("property_casing").and_then(|v| v.as_str()) {
+            config.property_casing = casing.parse()?;
+        }
+        if let Some(overrides) = value.get("namespace_overrides").and_then(|v| v.as_object()) {
+            for (package, namespace) in overrides {
+                if let Some(namespace) = namespace.as_str() {
+                    config.namespace_overrides.insert(package.clone(), namespace.to_string());
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
 /// Validated identifier for proto elements
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Identifier(String);
@@ -173,6 +520,41 @@ impl fmt::Display for PackageName {
     }
 }
 
+/// Mapping from `google.protobuf.*` well-known type full names to their
+/// idiomatic VB.NET representation. Wrapper types map to `Nullable(Of T)` for
+/// value types (reference types like `String`/`Byte()` are already
+/// nullable). On the wire, `Timestamp` and `Duration` are conventionally
+/// serialized as RFC3339 strings, so VB consumers must parse/format
+/// accordingly when crossing the JSON boundary.
+pub(crate) static WELL_KNOWN_VB_TYPES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+    "google.protobuf.Timestamp" => "Date",
+    "google.protobuf.Duration" => "TimeSpan",
+    "google.protobuf.Int32Value" => "Nullable(Of Integer)",
+    "google.protobuf.Int64Value" => "Nullable(Of Long)",
+    "google.protobuf.UInt32Value" => "Nullable(Of UInteger)",
+    "google.protobuf.UInt64Value" => "Nullable(Of ULong)",
+    "google.protobuf.FloatValue" => "Nullable(Of Single)",
+    "google.protobuf.DoubleValue" => "Nullable(Of Double)",
+    "google.protobuf.BoolValue" => "Nullable(Of Boolean)",
+    "google.protobuf.StringValue" => "String",
+    "google.protobuf.BytesValue" => "Byte()",
+    "google.protobuf.Any" => "Object",
+    "google.protobuf.Struct" => "Object",
+    "google.protobuf.Value" => "Object",
+};
+
+/// Build the fully-qualified `google.protobuf.*` name for a message type, if
+/// `package` is the well-known types package. Returns `None` otherwise, so
+/// callers can short-circuit the well-known-type lookup for ordinary
+/// user-defined messages.
+pub(crate) fn qualified_well_known_name(name: &str, package: Option<&PackageName>) -> Option<String> {
+    let package = package?;
+    if package.as_str() != "google.protobuf" {
+        return None;
+    }
+    Some(format!("{}.{}", package.as_str(), name))
+}
+
 /// Proto type with validation and conversion capabilities
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProtoType {
@@ -186,6 +568,12 @@ pub enum ProtoType {
         package: Option<PackageName>,
     },
     Repeated(Box<ProtoType>),
+    /// A proto3 `map<K, V>` field. Protobuf requires the key to be an
+    /// integral or string scalar, enforced by [`ProtoType::map`].
+    Map {
+        key: Box<ProtoType>,
+        value: Box<ProtoType>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -208,6 +596,11 @@ pub enum ScalarType {
 }
 
 impl ScalarType {
+    /// Whether this scalar is a legal proto3 map key (integral or string types).
+    pub fn is_valid_map_key(&self) -> bool {
+        !matches!(self, ScalarType::Float | ScalarType::Double | ScalarType::Bytes)
+    }
+
     pub fn to_vb_type(&self) -> &'static str {
         match self {
             ScalarType::String => "String",
@@ -257,18 +650,50 @@ impl FromStr for ScalarType {
 }
 
 impl ProtoType {
+    /// Construct a `map<K, V>` type, validating that `key` is a legal proto3
+    /// map key (an integral or string scalar).
+    pub fn map(key: ScalarType, value: ProtoType) -> Result<Self> {
+        if !key.is_valid_map_key() {
+            return Err(Error::validation_error(format!(
+                "Invalid map key type: {:?} (must be an integral or string scalar)",
+                key
+            )));
+        }
+        Ok(ProtoType::Map {
+            key: Box::new(ProtoType::Scalar(key)),
+            value: Box::new(value),
+        })
+    }
+
+    /// VB.NET type rendering, kept as a thin wrapper over [`TargetLanguage`]
+    /// for backward compatibility with existing callers.
     pub fn to_vb_type(&self, current_package: Option<&PackageName>) -> String {
+        self.to_target_type(&crate::target::VbNet, current_package)
+    }
+
+    /// Render this type's name for an arbitrary [`TargetLanguage`].
+    pub fn to_target_type(
+        &self,
+        target: &dyn crate::target::TargetLanguage,
+        current_package: Option<&PackageName>,
+    ) -> String {
         match self {
-            ProtoType::Scalar(scalar) => scalar.to_vb_type().to_string(),
+            ProtoType::Scalar(scalar) => target.scalar_type_name(scalar).to_string(),
             ProtoType::Message { name, package } => {
-                self.qualified_name(name, package.as_ref(), current_package)
+                qualified_well_known_name(name, package.as_ref())
+                    .and_then(|qualified| target.well_known_type(&qualified))
+                    .unwrap_or_else(|| self.qualified_name(name, package.as_ref(), current_package))
             }
             ProtoType::Enum { name, package } => {
                 self.qualified_name(name, package.as_ref(), current_package)
             }
             ProtoType::Repeated(inner) => {
-                format!("List(Of {})", inner.to_vb_type(current_package))
+                target.repeated_wrapper(&inner.to_target_type(target, current_package))
             }
+            ProtoType::Map { key, value } => target.map_wrapper(
+                &key.to_target_type(target, current_package),
+                &value.to_target_type(target, current_package),
+            ),
         }
     }
 
@@ -294,6 +719,17 @@ pub struct ProtoField {
     field_type: ProtoType,
     #[builder(default)]
     field_number: u32,
+    /// Set for a proto3 `optional` field: presence is tracked explicitly,
+    /// so a generator can distinguish "unset" from "set to the default
+    /// value" (e.g. emit a nullable member instead of a required one).
+    /// Never set for `repeated`/`map` fields.
+    #[builder(default)]
+    optional: bool,
+    /// Doc comment attached to this field while parsing: a same-line
+    /// trailing `// ...` comment if present, otherwise the comment block
+    /// immediately preceding the field declaration.
+    #[builder(default)]
+    docs: Option<String>,
 }
 
 impl ProtoField {
@@ -308,6 +744,46 @@ impl ProtoField {
     pub fn field_number(&self) -> u32 {
         self.field_number
     }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+}
+
+/// A proto3 `oneof` group: a named set of fields of which at most one may be
+/// set at a time.
+#[derive(Debug, Clone, Builder)]
+pub struct ProtoOneof {
+    name: Identifier,
+    #[builder(default)]
+    variants: Vec<ProtoField>,
+    /// Doc comment attached to this oneof while parsing (see
+    /// [`ProtoField::docs`]).
+    #[builder(default)]
+    docs: Option<String>,
+}
+
+impl ProtoOneof {
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    pub fn variants(&self) -> &[ProtoField] {
+        &self.variants
+    }
+
+    /// Name of the generated discriminator enum naming which variant is set.
+    pub fn discriminator_enum_name(&self) -> String {
+        format!("{}Case", to_pascal_case(self.name.as_str()))
+    }
+
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
 }
 
 /// Proto message with builder pattern
@@ -318,6 +794,12 @@ pub struct ProtoMessage {
     fields: Vec<ProtoField>,
     #[builder(default)]
     nested_messages: HashMap<String, ProtoMessage>,
+    #[builder(default)]
+    oneofs: Vec<ProtoOneof>,
+    /// Doc comment attached to this message while parsing (see
+    /// [`ProtoField::docs`]).
+    #[builder(default)]
+    docs: Option<String>,
 }
 
 impl ProtoMessage {
@@ -332,6 +814,14 @@ impl ProtoMessage {
     pub fn nested_messages(&self) -> &HashMap<String, ProtoMessage> {
         &self.nested_messages
     }
+
+    pub fn oneofs(&self) -> &[ProtoOneof] {
+        &self.oneofs
+    }
+
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
 }
 
 /// Proto enum with strong typing
@@ -340,6 +830,15 @@ pub struct ProtoEnum {
     name: Identifier,
     #[builder(default)]
     values: HashMap<String, i32>,
+    /// Doc comment attached to each enum value, keyed by value name (see
+    /// [`ProtoField::docs`]); values with no doc comment are absent here
+    /// rather than mapped to `None`.
+    #[builder(default)]
+    value_docs: HashMap<String, String>,
+    /// Doc comment attached to this enum while parsing (see
+    /// [`ProtoField::docs`]).
+    #[builder(default)]
+    docs: Option<String>,
 }
 
 impl ProtoEnum {
@@ -350,6 +849,14 @@ impl ProtoEnum {
     pub fn values(&self) -> &HashMap<String, i32> {
         &self.values
     }
+
+    pub fn value_docs(&self) -> &HashMap<String, String> {
+        &self.value_docs
+    }
+
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
 }
 
 /// Proto RPC method
@@ -362,6 +869,10 @@ pub struct ProtoRpc {
     client_streaming: bool,
     #[builder(default = "false")]
     server_streaming: bool,
+    /// Doc comment attached to this RPC while parsing (see
+    /// [`ProtoField::docs`]).
+    #[builder(default)]
+    docs: Option<String>,
 }
 
 impl ProtoRpc {
@@ -369,6 +880,10 @@ impl ProtoRpc {
         &self.name
     }
 
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
     pub fn input_type(&self) -> &ProtoType {
         &self.input_type
     }
@@ -381,6 +896,34 @@ impl ProtoRpc {
         !self.client_streaming && !self.server_streaming
     }
 
+    pub fn client_streaming(&self) -> bool {
+        self.client_streaming
+    }
+
+    pub fn server_streaming(&self) -> bool {
+        self.server_streaming
+    }
+
+    /// True for a server-streaming RPC with a single (non-streamed) request,
+    /// the only streaming shape generators in this crate can emit an HTTP
+    /// client for (chunked/NDJSON reads).
+    pub fn is_server_streaming_only(&self) -> bool {
+        self.server_streaming && !self.client_streaming
+    }
+
+    /// True for a pure client-streaming RPC (no server streaming): the
+    /// caller supplies a sequence of requests and gets back one response.
+    pub fn is_client_streaming_only(&self) -> bool {
+        self.client_streaming && !self.server_streaming
+    }
+
+    /// True for a bidirectional-streaming RPC, which has requests and
+    /// responses flowing independently and so has no single buffered
+    /// request/response-JSON-over-HTTP mapping.
+    pub fn is_bidi_streaming(&self) -> bool {
+        self.client_streaming && self.server_streaming
+    }
+
     /// Convert RPC name to kebab-case for URL (excluding trailing version suffix like V2)
     pub fn url_name(&self) -> String {
         let base = self.base_name_without_version();
@@ -428,6 +971,10 @@ pub struct ProtoService {
     name: Identifier,
     #[builder(default)]
     rpcs: Vec<ProtoRpc>,
+    /// Doc comment attached to this service while parsing (see
+    /// [`ProtoField::docs`]).
+    #[builder(default)]
+    docs: Option<String>,
 }
 
 impl ProtoService {
@@ -435,6 +982,10 @@ impl ProtoService {
         &self.name
     }
 
+    pub fn docs(&self) -> Option<&str> {
+        self.docs.as_deref()
+    }
+
     pub fn rpcs(&self) -> &[ProtoRpc] {
         &self.rpcs
     }
@@ -457,6 +1008,10 @@ pub struct ProtoFile {
     enums: HashMap<String, ProtoEnum>,
     #[builder(default)]
     services: Vec<ProtoService>,
+    /// Raw `import "...";` paths, in source order, as written in the file
+    /// (not yet resolved to filesystem paths — see [`crate::imports`]).
+    #[builder(default)]
+    imports: Vec<String>,
 }
 
 impl ProtoFile {
@@ -480,11 +1035,24 @@ impl ProtoFile {
         &self.services
     }
 
-    /// Get the default namespace for this file
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    /// Get the default namespace for this file, rendered for VB.NET. Kept
+    /// as a thin wrapper over [`Self::default_namespace_for`] for backward
+    /// compatibility with existing callers.
     pub fn default_namespace(&self) -> String {
+        self.default_namespace_for(&crate::target::VbNet)
+    }
+
+    /// Get the default namespace/module name for this file, in an arbitrary
+    /// [`crate::target::TargetLanguage`]'s form. Falls back to the file
+    /// name (PascalCased) when the proto has no package.
+    pub fn default_namespace_for(&self, target: &dyn crate::target::TargetLanguage) -> String {
         self.package
             .as_ref()
-            .map(|p| p.to_vb_namespace())
+            .map(|p| target.namespace_from_package(p))
             .unwrap_or_else(|| {
                 std::path::Path::new(&self.file_name)
                     .file_stem()
@@ -577,6 +1145,13 @@ pub fn to_pascal_case(name: &str) -> String {
         .collect()
 }
 
+/// Convert to `snake_case`, e.g. for idiomatic Rust field/module names.
+/// Reuses [`to_kebab_case`]'s word-boundary detection and swaps the
+/// separator, so the two stay consistent for the same input.
+pub fn to_snake_case(name: &str) -> String {
+    to_kebab_case(name).replace('-', "_")
+}
+
 /// Escape VB.NET reserved keywords by wrapping them in square brackets.
 ///
 /// # Arguments
@@ -652,4 +1227,59 @@ mod tests {
         assert_eq!(net40hwr.method_suffix(), "");
         assert_eq!(net40hwr.http_client_type(), "HttpWebRequest");
     }
+
+    #[test]
+    fn test_wire_protocol_parsing() {
+        assert_eq!("legacy".parse::<WireProtocol>().unwrap(), WireProtocol::Legacy);
+        assert_eq!("twirp".parse::<WireProtocol>().unwrap(), WireProtocol::Twirp);
+        assert_eq!("TWIRP".parse::<WireProtocol>().unwrap(), WireProtocol::Twirp);
+        assert_eq!("connect".parse::<WireProtocol>().unwrap(), WireProtocol::Connect);
+        assert_eq!(WireProtocol::default(), WireProtocol::Legacy);
+        assert!("invalid".parse::<WireProtocol>().is_err());
+    }
+
+    #[test]
+    fn test_serialization_format_parsing() {
+        assert_eq!("json".parse::<SerializationFormat>().unwrap(), SerializationFormat::Json);
+        assert_eq!("protobuf".parse::<SerializationFormat>().unwrap(), SerializationFormat::Protobuf);
+        assert_eq!("PROTOBUF".parse::<SerializationFormat>().unwrap(), SerializationFormat::Protobuf);
+        assert_eq!(SerializationFormat::default(), SerializationFormat::Json);
+        assert!("invalid".parse::<SerializationFormat>().is_err());
+    }
+
+    #[test]
+    fn test_well_known_type_mapping() {
+        let pkg = PackageName::new("google.protobuf").unwrap();
+
+        let timestamp = ProtoType::Message {
+            name: "Timestamp".to_string(),
+            package: Some(pkg.clone()),
+        };
+        assert_eq!(timestamp.to_vb_type(None), "Date");
+
+        let int32_value = ProtoType::Message {
+            name: "Int32Value".to_string(),
+            package: Some(pkg.clone()),
+        };
+        assert_eq!(int32_value.to_vb_type(None), "Nullable(Of Integer)");
+
+        let bytes_value = ProtoType::Message {
+            name: "BytesValue".to_string(),
+            package: Some(pkg.clone()),
+        };
+        assert_eq!(bytes_value.to_vb_type(None), "Byte()");
+
+        let any = ProtoType::Message {
+            name: "Any".to_string(),
+            package: Some(pkg),
+        };
+        assert_eq!(any.to_vb_type(None), "Object");
+
+        // A message named "Timestamp" outside google.protobuf is unaffected
+        let unrelated = ProtoType::Message {
+            name: "Timestamp".to_string(),
+            package: Some(PackageName::new("myapp").unwrap()),
+        };
+        assert_eq!(unrelated.to_vb_type(None), "Myapp.Timestamp");
+    }
 }
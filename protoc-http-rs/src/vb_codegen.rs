@@ -8,12 +8,345 @@ use std::path::{Path, PathBuf};
 pub struct VbNetGenerator {
     namespace: Option<String>,
     compat_mode: CompatibilityMode,
+    /// Opt-in emission of streaming RPC methods: server-streaming (see
+    /// [`Self::generate_streaming_rpc_methods_net45`]), client-streaming
+    /// (buffered into one NDJSON body; see
+    /// [`Self::generate_client_streaming_rpc_methods_net45`]), and bidi (see
+    /// [`Self::generate_bidi_streaming_rpc_methods_net45`] — unsupported in
+    /// `Net40Hwr` mode, which emits a `NotSupportedException` stub instead).
+    streaming: bool,
+    /// Routing convention and error envelope the generated client speaks.
+    /// Defaults to [`WireProtocol::Legacy`].
+    wire_protocol: WireProtocol,
+    /// Gzip the request body and transparently decompress responses that
+    /// come back `Content-Encoding: gzip`/`deflate`. Off by default.
+    enable_compression: bool,
+    /// Wire payload encoding. Defaults to [`SerializationFormat::Json`].
+    serialization_format: SerializationFormat,
+    /// Retry transient failures (connection errors, 502/503/504, and 429
+    /// honoring `Retry-After`) with exponential backoff and jitter. Off by
+    /// default; when on, the generated `FooClient` exposes tuning
+    /// parameters as constructor arguments.
+    enable_retry: bool,
+    /// Which side(s) of the HTTP/JSON gateway to emit: the `FooClient`, the
+    /// `FooControllerBase` ASP.NET server stub, or both. Defaults to
+    /// [`GenerationTarget::Client`], the generator's original behavior.
+    generation_target: GenerationTarget,
+    /// How the generated `FooClient` authenticates each outgoing call.
+    /// Defaults to [`CredentialMode::None`].
+    credential_mode: CredentialMode,
+    /// Overrides for generated type/method names, JSON property casing, and
+    /// per-package namespaces, consulted at every identifier emission
+    /// point. Defaults to [`NamingConfig::default`] (no overrides, camelCase
+    /// properties), the generator's original behavior.
+    naming: NamingConfig,
+    /// Minimum serialized request-body size, in bytes, before
+    /// [`Self::enable_compression`] gzips it; bodies at or under the
+    /// threshold are sent uncompressed. Defaults to `0`, meaning every
+    /// request body is compressed whenever compression is on, the
+    /// generator's original behavior. Only gates the unary `PostJsonAsync`/
+    /// `PostJson` request path; streaming request bodies always compress
+    /// when [`Self::enable_compression`] is set, regardless of size.
+    compression_threshold_bytes: usize,
 }
 
 impl VbNetGenerator {
     /// Create a new VB.NET generator with optional custom namespace and compatibility mode
     pub fn new(namespace: Option<String>, compat_mode: CompatibilityMode) -> Self {
-        Self { namespace, compat_mode }
+        Self {
+            namespace,
+            compat_mode,
+            streaming: false,
+            wire_protocol: WireProtocol::default(),
+            enable_compression: false,
+            serialization_format: SerializationFormat::default(),
+            enable_retry: false,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator that also emits server-streaming RPC
+    /// methods (chunked/NDJSON reads), gated behind the CLI's `--streaming` flag.
+    pub fn with_streaming(namespace: Option<String>, compat_mode: CompatibilityMode, streaming: bool) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol: WireProtocol::default(),
+            enable_compression: false,
+            serialization_format: SerializationFormat::default(),
+            enable_retry: false,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming and
+    /// wire protocol, gated behind the CLI's `--streaming`/`--wire-protocol` flags.
+    pub fn with_wire_protocol(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression: false,
+            serialization_format: SerializationFormat::default(),
+            enable_retry: false,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, and request/response compression, gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression` flags.
+    pub fn with_compression(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format: SerializationFormat::default(),
+            enable_retry: false,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression, and request/response serialization format,
+    /// gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--serialization-format` flags.
+    pub fn with_serialization_format(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry: false,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression, serialization format, and automatic retry of
+    /// transient failures, gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--serialization-format`/`--enable-retry` flags.
+    pub fn with_retry(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+        enable_retry: bool,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target: GenerationTarget::default(),
+            credential_mode: CredentialMode::default(),
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression, serialization format, automatic retry, and
+    /// which side(s) of the HTTP/JSON gateway to emit, gated behind the
+    /// CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--serialization-format`/`--enable-retry`/`--generation-target` flags.
+    pub fn with_generation_target(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+        enable_retry: bool,
+        generation_target: GenerationTarget,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode: CredentialMode::default(),
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression, serialization format, automatic retry, which
+    /// side(s) of the HTTP/JSON gateway to emit, and per-call
+    /// authentication, gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--serialization-format`/`--enable-retry`/`--generation-target`/`--credential-mode` flags.
+    pub fn with_credentials(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+        enable_retry: bool,
+        generation_target: GenerationTarget,
+        credential_mode: CredentialMode,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode,
+            naming: NamingConfig::default(),
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression, serialization format, automatic retry, which
+    /// side(s) of the HTTP/JSON gateway to emit, per-call authentication,
+    /// and naming overrides, gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--serialization-format`/`--enable-retry`/`--generation-target`/`--credential-mode`/`--naming-config` flags.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_naming_config(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+        enable_retry: bool,
+        generation_target: GenerationTarget,
+        credential_mode: CredentialMode,
+        naming: NamingConfig,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode,
+            naming,
+            compression_threshold_bytes: 0,
+        }
+    }
+
+    /// Create a new VB.NET generator with full control over streaming, wire
+    /// protocol, compression (and the request-body size threshold it's
+    /// gated on), serialization format, automatic retry, which side(s) of
+    /// the HTTP/JSON gateway to emit, per-call authentication, and naming
+    /// overrides, gated behind the CLI's
+    /// `--streaming`/`--wire-protocol`/`--enable-compression`/`--compression-threshold-bytes`/`--serialization-format`/`--enable-retry`/`--generation-target`/`--credential-mode`/`--naming-config` flags.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_compression_threshold(
+        namespace: Option<String>,
+        compat_mode: CompatibilityMode,
+        streaming: bool,
+        wire_protocol: WireProtocol,
+        enable_compression: bool,
+        serialization_format: SerializationFormat,
+        enable_retry: bool,
+        generation_target: GenerationTarget,
+        credential_mode: CredentialMode,
+        naming: NamingConfig,
+        compression_threshold_bytes: usize,
+    ) -> Self {
+        Self {
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode,
+            naming,
+            compression_threshold_bytes,
+        }
+    }
+
+    /// The generated name for the proto identifier `original`, honoring
+    /// [`NamingConfig::resolve_type_name`].
+    fn resolve_type_name(&self, original: &str) -> String {
+        self.naming.resolve_type_name(original)
+    }
+
+    /// Rewrite every identifier token in a rendered VB type expression
+    /// (`HelloRequest`, `List(Of HelloRequest)`, a cross-package
+    /// `Foo.HelloRequest`, ...) that has a [`NamingConfig`] type override,
+    /// leaving surrounding generic syntax and namespace qualifiers
+    /// untouched.
+    fn apply_type_overrides(&self, vb_type: &str) -> String {
+        let mut result = String::with_capacity(vb_type.len());
+        let mut chars = vb_type.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c.is_alphabetic() || c == '_' {
+                let mut end = start + c.len_utf8();
+                while let Some(&(_, next)) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' {
+                        end += next.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                result.push_str(&self.resolve_type_name(&vb_type[start..end]));
+            } else {
+                result.push(c);
+            }
+        }
+        result
     }
 
     /// Generate VB.NET imports section based on compatibility mode
@@ -30,6 +363,15 @@ impl VbNetGenerator {
                     "Imports System.Collections.Generic",
                     "Imports Newtonsoft.Json",
                 ]);
+                if self.streaming || self.enable_compression || self.serialization_format == SerializationFormat::Protobuf {
+                    imports.push("Imports System.IO");
+                }
+                if self.enable_compression
+                    || self.serialization_format == SerializationFormat::Protobuf
+                    || self.credential_mode == CredentialMode::BearerToken
+                {
+                    imports.push("Imports System.Net.Http.Headers");
+                }
             }
             CompatibilityMode::Net40Hwr => {
                 imports.extend([
@@ -39,24 +381,57 @@ impl VbNetGenerator {
                     "Imports System.Collections.Generic",
                     "Imports Newtonsoft.Json",
                 ]);
+                if self.enable_retry {
+                    imports.push("Imports System.Threading");
+                }
+                // Server controller actions are always `Async Function ... As Task(Of T)`,
+                // regardless of which compatibility mode the client side targets.
+                if self.generation_target.includes_server() {
+                    imports.push("Imports System.Threading.Tasks");
+                }
             }
         }
-        
+
+        if self.enable_compression {
+            imports.push("Imports System.IO.Compression");
+        }
+
+        if matches!(self.wire_protocol, WireProtocol::Twirp | WireProtocol::Connect) {
+            imports.push("Imports Newtonsoft.Json.Linq");
+        }
+
+        if self.serialization_format == SerializationFormat::Protobuf {
+            imports.push("Imports ProtoBuf");
+        }
+
+        if self.generation_target.includes_server() {
+            imports.push("Imports Microsoft.AspNetCore.Mvc");
+        }
+
         imports.join("\n") + "\n"
     }
 
-    /// Generate namespace declaration
+    /// Generate namespace declaration. The explicit `--namespace` override
+    /// takes precedence (it targets a single run's whole output); absent
+    /// that, a per-package [`NamingConfig::resolve_namespace`] override
+    /// applies; otherwise falls back to the proto package, PascalCased.
     fn generate_namespace(&self, proto: &ProtoFile) -> String {
         let default_ns = proto.default_namespace();
-        let ns = self.namespace.as_ref().unwrap_or(&default_ns);
+        let ns = self.namespace.clone().unwrap_or_else(|| {
+            self.naming.resolve_namespace(proto.package().map(|pkg| pkg.as_str()), default_ns)
+        });
         format!("Namespace {}", ns)
     }
 
-    /// Generate enum definitions using functional approach
+    /// Generate enum definitions using functional approach, sorted by name
+    /// for deterministic output (proto enums are stored in a `HashMap`, so
+    /// iteration order alone isn't stable across runs).
     fn generate_enums(&self, proto: &ProtoFile) -> String {
-        proto
-            .enums()
-            .values()
+        let mut enums: Vec<_> = proto.enums().values().collect();
+        enums.sort_by_key(|proto_enum| proto_enum.name().as_str());
+
+        enums
+            .into_iter()
             .map(|proto_enum| self.generate_enum(proto_enum))
             .collect::<Vec<_>>()
             .join("\n\n")
@@ -65,9 +440,11 @@ impl VbNetGenerator {
     /// Generate a single enum
     fn generate_enum(&self, proto_enum: &ProtoEnum) -> String {
         let enum_name = proto_enum.name();
-        let values = proto_enum
-            .values()
-            .iter()
+        let mut values: Vec<_> = proto_enum.values().iter().collect();
+        values.sort_by_key(|(_, value)| **value);
+
+        let values = values
+            .into_iter()
             .map(|(key, value)| format!("        {} = {}", key, value))
             .collect::<Vec<_>>()
             .join("\n");
@@ -75,11 +452,14 @@ impl VbNetGenerator {
         format!("    Public Enum {}\n{}\n    End Enum", enum_name, values)
     }
 
-    /// Generate message definitions using functional approach
+    /// Generate message definitions using functional approach, sorted by
+    /// name for deterministic output (see [`Self::generate_enums`]).
     fn generate_messages(&self, proto: &ProtoFile) -> String {
-        proto
-            .messages()
-            .values()
+        let mut messages: Vec<_> = proto.messages().values().collect();
+        messages.sort_by_key(|message| message.name().as_str());
+
+        messages
+            .into_iter()
             .map(|message| self.generate_message(message, proto, 1))
             .collect::<Vec<_>>()
             .join("\n\n")
@@ -96,15 +476,22 @@ impl VbNetGenerator {
         let mut lines = Vec::new();
 
         // Class declaration
-        lines.push(format!("{}Public Class {}", indent, message.name()));
+        if self.serialization_format == SerializationFormat::Protobuf {
+            lines.push(format!("{}<ProtoContract()>", indent));
+        }
+        lines.push(format!("{}Public Class {}", indent, self.resolve_type_name(message.name().as_str())));
 
         // Fields as properties
         for field in message.fields() {
-            let prop_type = field.field_type().to_vb_type(proto.package());
-            let json_name = to_camel_case(field.name().as_str());
+            let prop_type = self.apply_type_overrides(&field.field_type().to_vb_type(proto.package()));
             let prop_name = to_pascal_case(field.name().as_str());
 
-            lines.push(format!("{}    <JsonProperty(\"{}\")>", indent, json_name));
+            if self.serialization_format == SerializationFormat::Protobuf {
+                lines.push(format!("{}    <ProtoMember({})>", indent, field.field_number()));
+            } else {
+                let json_name = self.naming.property_casing().apply(field.name().as_str());
+                lines.push(format!("{}    <JsonProperty(\"{}\")>", indent, json_name));
+            }
             lines.push(format!(
                 "{}    Public Property {} As {}",
                 indent, prop_name, prop_type
@@ -112,8 +499,15 @@ impl VbNetGenerator {
             lines.push("".to_string());
         }
 
+        // Oneof groups: a discriminator enum plus one nullable property per variant
+        for oneof in message.oneofs() {
+            lines.push(self.generate_oneof(oneof, proto, indent_level));
+        }
+
         // Nested messages
-        for nested in message.nested_messages().values() {
+        let mut nested_messages: Vec<_> = message.nested_messages().values().collect();
+        nested_messages.sort_by_key(|nested| nested.name().as_str());
+        for nested in nested_messages {
             lines.push(self.generate_message(nested, proto, indent_level + 1));
         }
 
@@ -123,6 +517,189 @@ impl VbNetGenerator {
         lines.join("\n")
     }
 
+    /// Generate a `oneof` group as a discriminator enum plus one nullable
+    /// property per variant.
+    fn generate_oneof(&self, oneof: &ProtoOneof, proto: &ProtoFile, indent_level: usize) -> String {
+        let indent = "    ".repeat(indent_level);
+        let enum_name = oneof.discriminator_enum_name();
+        let mut lines = Vec::new();
+
+        lines.push(format!("{}Public Enum {}", indent, enum_name));
+        lines.push(format!("{}    None", indent));
+        for variant in oneof.variants() {
+            lines.push(format!(
+                "{}    {}",
+                indent,
+                to_pascal_case(variant.name().as_str())
+            ));
+        }
+        lines.push(format!("{}End Enum", indent));
+        lines.push("".to_string());
+
+        lines.push(format!(
+            "{}Public Property {}Case As {}",
+            indent,
+            to_pascal_case(oneof.name().as_str()),
+            enum_name
+        ));
+        lines.push("".to_string());
+
+        for variant in oneof.variants() {
+            let prop_type = self.apply_type_overrides(&variant.field_type().to_vb_type(proto.package()));
+            let prop_name = to_pascal_case(variant.name().as_str());
+
+            if self.serialization_format == SerializationFormat::Protobuf {
+                lines.push(format!("{}<ProtoMember({})>", indent, variant.field_number()));
+            } else {
+                let json_name = self.naming.property_casing().apply(variant.name().as_str());
+                lines.push(format!("{}<JsonProperty(\"{}\")>", indent, json_name));
+            }
+            lines.push(format!(
+                "{}Public Property {} As {}",
+                indent, prop_name, prop_type
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Generate the `TwirpException` class used by [`WireProtocol::Twirp`]
+    /// clients to surface a Twirp error envelope (`{"code", "msg", "meta"}`)
+    /// as a typed exception instead of a raw `HttpRequestException`/`WebException`.
+    fn generate_twirp_exception_class(&self) -> String {
+        [
+            "    Public Class TwirpException",
+            "        Inherits Exception",
+            "",
+            "        Public ReadOnly Property Code As String",
+            "        Public ReadOnly Property Msg As String",
+            "        Public ReadOnly Property Meta As Dictionary(Of String, String)",
+            "",
+            "        Public Sub New(code As String, msg As String, meta As Dictionary(Of String, String))",
+            "            MyBase.New(msg)",
+            "            Me.Code = code",
+            "            Me.Msg = msg",
+            "            Me.Meta = If(meta, New Dictionary(Of String, String)())",
+            "        End Sub",
+            "",
+            "        ''' <summary>",
+            "        ''' Parse a Twirp error envelope from a non-2xx response body, falling",
+            "        ''' back to a generic \"internal\" error if the body isn't valid Twirp JSON.",
+            "        ''' </summary>",
+            "        Public Shared Function FromJson(body As String) As TwirpException",
+            "            Try",
+            "                Dim envelope As JObject = JObject.Parse(body)",
+            "                Dim code As String = If(envelope(\"code\")?.ToString(), \"internal\")",
+            "                Dim msg As String = If(envelope(\"msg\")?.ToString(), body)",
+            "                Dim meta As New Dictionary(Of String, String)",
+            "                Dim metaToken = envelope(\"meta\")",
+            "                If metaToken IsNot Nothing Then",
+            "                    For Each prop As JProperty In CType(metaToken, JObject).Properties()",
+            "                        meta(prop.Name) = prop.Value.ToString()",
+            "                    Next",
+            "                End If",
+            "                Return New TwirpException(code, msg, meta)",
+            "            Catch",
+            "                Return New TwirpException(\"internal\", body, New Dictionary(Of String, String)())",
+            "            End Try",
+            "        End Function",
+            "    End Class",
+        ]
+        .join("\n")
+    }
+
+    /// Generate the `ConnectCode`/`ConnectError` types used by
+    /// [`WireProtocol::Connect`] clients to surface a Connect error envelope
+    /// (`{"code", "message", "details"}`) as a typed exception carrying one
+    /// of Connect's canonical error codes, instead of a raw
+    /// `HttpRequestException`/`WebException`.
+    fn generate_connect_error_class(&self) -> String {
+        [
+            "    Public Enum ConnectCode",
+            "        Canceled",
+            "        Unknown",
+            "        InvalidArgument",
+            "        DeadlineExceeded",
+            "        NotFound",
+            "        AlreadyExists",
+            "        PermissionDenied",
+            "        ResourceExhausted",
+            "        FailedPrecondition",
+            "        Aborted",
+            "        OutOfRange",
+            "        Unimplemented",
+            "        Internal",
+            "        Unavailable",
+            "        DataLoss",
+            "        Unauthenticated",
+            "    End Enum",
+            "",
+            "    Public Class ConnectError",
+            "        Inherits Exception",
+            "",
+            "        Public ReadOnly Property Code As ConnectCode",
+            "",
+            "        Public Sub New(code As ConnectCode, message As String)",
+            "            MyBase.New(message)",
+            "            Me.Code = code",
+            "        End Sub",
+            "",
+            "        ''' <summary>",
+            "        ''' Parse a Connect error envelope from a non-2xx response body, falling",
+            "        ''' back to a generic \"internal\" error if the body isn't valid Connect JSON.",
+            "        ''' </summary>",
+            "        Public Shared Function FromJson(body As String) As ConnectError",
+            "            Try",
+            "                Dim envelope As JObject = JObject.Parse(body)",
+            "                Dim code As ConnectCode = ParseCode(envelope(\"code\")?.ToString())",
+            "                Dim message As String = If(envelope(\"message\")?.ToString(), body)",
+            "                Return New ConnectError(code, message)",
+            "            Catch",
+            "                Return New ConnectError(ConnectCode.Internal, body)",
+            "            End Try",
+            "        End Function",
+            "",
+            "        Private Shared Function ParseCode(code As String) As ConnectCode",
+            "            Select Case code",
+            "                Case \"canceled\"",
+            "                    Return ConnectCode.Canceled",
+            "                Case \"invalid_argument\"",
+            "                    Return ConnectCode.InvalidArgument",
+            "                Case \"deadline_exceeded\"",
+            "                    Return ConnectCode.DeadlineExceeded",
+            "                Case \"not_found\"",
+            "                    Return ConnectCode.NotFound",
+            "                Case \"already_exists\"",
+            "                    Return ConnectCode.AlreadyExists",
+            "                Case \"permission_denied\"",
+            "                    Return ConnectCode.PermissionDenied",
+            "                Case \"resource_exhausted\"",
+            "                    Return ConnectCode.ResourceExhausted",
+            "                Case \"failed_precondition\"",
+            "                    Return ConnectCode.FailedPrecondition",
+            "                Case \"aborted\"",
+            "                    Return ConnectCode.Aborted",
+            "                Case \"out_of_range\"",
+            "                    Return ConnectCode.OutOfRange",
+            "                Case \"unimplemented\"",
+            "                    Return ConnectCode.Unimplemented",
+            "                Case \"internal\"",
+            "                    Return ConnectCode.Internal",
+            "                Case \"unavailable\"",
+            "                    Return ConnectCode.Unavailable",
+            "                Case \"data_loss\"",
+            "                    Return ConnectCode.DataLoss",
+            "                Case \"unauthenticated\"",
+            "                    Return ConnectCode.Unauthenticated",
+            "                Case Else",
+            "                    Return ConnectCode.Unknown",
+            "            End Select",
+            "        End Function",
+            "    End Class",
+        ]
+        .join("\n")
+    }
+
     /// Generate service client definitions using functional approach
     fn generate_services(&self, proto: &ProtoFile) -> String {
         proto
@@ -141,26 +718,136 @@ impl VbNetGenerator {
         }
     }
 
+    /// Generate `FooControllerBase` ASP.NET server stub definitions for
+    /// every service, the other side of [`Self::generate_services`]' HTTP
+    /// clients.
+    fn generate_server_controllers(&self, proto: &ProtoFile) -> String {
+        proto
+            .services()
+            .iter()
+            .map(|service| self.generate_server_controller(service, proto))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Generate an abstract ASP.NET controller base for a single service:
+    /// one concrete, routed action per unary RPC that deserializes the
+    /// request and delegates to an `Async Function`/`Function` the user
+    /// overrides, mirroring [`Self::generate_service_net45`]'s validation
+    /// and JSON glue but on the receiving end. Always async/`Task`-based,
+    /// independent of [`Self::compat_mode`] (which only governs the client
+    /// side's HTTP surface).
+    fn generate_server_controller(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        let controller_name = format!("{}ControllerBase", self.resolve_type_name(service.name().as_str()));
+        let mut lines = vec![
+            format!("    Public MustInherit Class {}", controller_name),
+            "        Inherits ControllerBase".to_string(),
+            "".to_string(),
+        ];
+
+        for rpc in service.unary_rpcs() {
+            lines.extend(self.generate_server_rpc_action(service, rpc, proto));
+            lines.push("".to_string());
+        }
+
+        lines.push("    End Class".to_string());
+        lines.join("\n")
+    }
+
+    /// Generate the routed action method plus the abstract method it
+    /// delegates to, for one unary RPC.
+    fn generate_server_rpc_action(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+        let method_name = self.resolve_type_name(rpc.name().as_str());
+
+        vec![
+            format!("        <HttpPost({})>", relative_path),
+            format!(
+                "        Public Async Function {}Endpoint(<FromBody> request As {}) As Task(Of IActionResult)",
+                method_name, input_type
+            ),
+            "            If request Is Nothing Then Throw New ArgumentException(\"request cannot be null\")".to_string(),
+            format!("            Dim result As {} = Await {}(request)", output_type, method_name),
+            "            Return Ok(result)".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            format!(
+                "        Public MustOverride Function {}(request As {}) As Task(Of {})",
+                method_name, input_type, output_type
+            ),
+        ]
+    }
+
     /// Generate service client for .NET 4.5 mode (HttpClient + async/await)
     fn generate_service_net45(&self, service: &ProtoService, proto: &ProtoFile) -> String {
         let mut lines = Vec::new();
-        let client_name = format!("{}Client", service.name());
+        let client_name = format!("{}Client", self.resolve_type_name(service.name().as_str()));
 
         // Class declaration and fields
         lines.extend([
             format!("    Public Class {}", client_name),
             "        Private ReadOnly _http As HttpClient".to_string(),
             "        Private ReadOnly _baseUrl As String".to_string(),
-            "".to_string(),
         ]);
-
-        // Constructor
+        if self.enable_retry {
+            lines.extend([
+                "        Private ReadOnly _maxAttempts As Integer".to_string(),
+                "        Private ReadOnly _baseDelayMs As Integer".to_string(),
+                "        Private ReadOnly _maxDelayMs As Integer".to_string(),
+                "        Private ReadOnly _random As New Random()".to_string(),
+            ]);
+        }
+        if self.credential_mode == CredentialMode::BearerToken {
+            lines.push("        Private ReadOnly _tokenProvider As Func(Of Task(Of String))".to_string());
+        }
+        lines.push("".to_string());
+
+        // Constructor. `apiKey` is a required parameter, so it must come
+        // right after `baseUrl` and before any `Optional` ones - VB.NET
+        // doesn't allow a required parameter after an optional one.
+        let mut ctor_params = "http As HttpClient, baseUrl As String".to_string();
+        if self.credential_mode == CredentialMode::ApiKey {
+            ctor_params.push_str(", apiKey As String");
+        }
+        match (self.enable_retry, self.credential_mode) {
+            (true, CredentialMode::BearerToken) => ctor_params.push_str(", Optional tokenProvider As Func(Of Task(Of String)) = Nothing, Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000"),
+            (true, _) => ctor_params.push_str(", Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000"),
+            (false, CredentialMode::BearerToken) => ctor_params.push_str(", Optional tokenProvider As Func(Of Task(Of String)) = Nothing"),
+            (false, _) => {}
+        }
         lines.extend([
-            "        Public Sub New(http As HttpClient, baseUrl As String)".to_string(),
+            format!("        Public Sub New({})", ctor_params),
             "            If http Is Nothing Then Throw New ArgumentNullException(NameOf(http))".to_string(),
             "            If String.IsNullOrWhiteSpace(baseUrl) Then Throw New ArgumentException(\"baseUrl cannot be null or empty\")".to_string(),
             "            _http = http".to_string(),
-            "            _baseUrl = baseUrl.TrimEnd(\"/\"c)".to_string(),
+        ]);
+        if self.wire_protocol == WireProtocol::Connect {
+            lines.push("            _http.DefaultRequestHeaders.Add(\"Connect-Protocol-Version\", \"1\")".to_string());
+        }
+        if self.enable_compression {
+            lines.push("            _http.DefaultRequestHeaders.Add(\"Accept-Encoding\", \"gzip, deflate\")".to_string());
+        }
+        if self.credential_mode == CredentialMode::ApiKey {
+            lines.extend([
+                "            If String.IsNullOrWhiteSpace(apiKey) Then Throw New ArgumentException(\"apiKey cannot be null or empty\")".to_string(),
+                "            _http.DefaultRequestHeaders.Add(\"X-Api-Key\", apiKey)".to_string(),
+            ]);
+        }
+        lines.push("            _baseUrl = baseUrl.TrimEnd(\"/\"c)".to_string());
+        if self.credential_mode == CredentialMode::BearerToken {
+            lines.push("            _tokenProvider = tokenProvider".to_string());
+        }
+        if self.enable_retry {
+            lines.extend([
+                "            If maxAttempts < 1 Then Throw New ArgumentOutOfRangeException(NameOf(maxAttempts))".to_string(),
+                "            _maxAttempts = maxAttempts".to_string(),
+                "            _baseDelayMs = baseDelayMs".to_string(),
+                "            _maxDelayMs = maxDelayMs".to_string(),
+            ]);
+        }
+        lines.extend([
             "        End Sub".to_string(),
             "".to_string(),
         ]);
@@ -170,45 +857,57 @@ impl VbNetGenerator {
             "        Private Async Function PostJsonAsync(Of TReq, TResp)(relativePath As String, request As TReq, cancellationToken As CancellationToken, Optional timeoutMs As Integer? = Nothing) As Task(Of TResp)".to_string(),
             "            If request Is Nothing Then Throw New ArgumentNullException(NameOf(request))".to_string(),
             "            Dim url As String = String.Format(\"{0}/{1}\", _baseUrl, relativePath.TrimStart(\"/\"c))".to_string(),
-            "            Dim json As String = JsonConvert.SerializeObject(request)".to_string(),
-            "            Using content As New StringContent(json, Encoding.UTF8, \"application/json\")".to_string(),
-            "                If timeoutMs.HasValue Then".to_string(),
-            "                    Using timeoutCts As New CancellationTokenSource(timeoutMs.Value)".to_string(),
-            "                        Using combined As CancellationTokenSource = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken, timeoutCts.Token)".to_string(),
-            "                            Dim response As HttpResponseMessage = Await _http.PostAsync(url, content, combined.Token).ConfigureAwait(False)".to_string(),
-            "                            If Not response.IsSuccessStatusCode Then".to_string(),
-            "                                Dim body As String = Await response.Content.ReadAsStringAsync().ConfigureAwait(False)".to_string(),
-            "                                Throw New HttpRequestException($\"Request failed with status {(CInt(response.StatusCode))} ({response.ReasonPhrase}): {body}\")".to_string(),
-            "                            End If".to_string(),
-            "                            Dim respJson As String = Await response.Content.ReadAsStringAsync().ConfigureAwait(False)".to_string(),
-            "                            If String.IsNullOrWhiteSpace(respJson) Then".to_string(),
-            "                                Throw New InvalidOperationException(\"Received empty response from server\")".to_string(),
-            "                            End If".to_string(),
-            "                            Return JsonConvert.DeserializeObject(Of TResp)(respJson)".to_string(),
-            "                        End Using".to_string(),
-            "                    End Using".to_string(),
-            "                Else".to_string(),
-            "                    Dim response As HttpResponseMessage = Await _http.PostAsync(url, content, cancellationToken).ConfigureAwait(False)".to_string(),
-            "                    If Not response.IsSuccessStatusCode Then".to_string(),
-            "                        Dim body As String = Await response.Content.ReadAsStringAsync().ConfigureAwait(False)".to_string(),
-            "                        Throw New HttpRequestException($\"Request failed with status {(CInt(response.StatusCode))} ({response.ReasonPhrase}): {body}\")".to_string(),
-            "                    End If".to_string(),
-            "                    Dim respJson As String = Await response.Content.ReadAsStringAsync().ConfigureAwait(False)".to_string(),
-            "                    If String.IsNullOrWhiteSpace(respJson) Then".to_string(),
-            "                        Throw New InvalidOperationException(\"Received empty response from server\")".to_string(),
-            "                    End If".to_string(),
-            "                    Return JsonConvert.DeserializeObject(Of TResp)(respJson)".to_string(),
-            "                End If".to_string(),
-            "            End Using".to_string(),
-            "        End Function".to_string(),
-            "".to_string(),
         ]);
+        let send_base = if self.enable_retry {
+            lines.push("            Dim attempt As Integer = 0".to_string());
+            lines.push("            Do".to_string());
+            lines.push("                Try".to_string());
+            "                    "
+        } else {
+            "            "
+        };
+        if self.serialization_format == SerializationFormat::Json {
+            lines.push(format!("{}Dim json As String = JsonConvert.SerializeObject(request)", send_base));
+        }
+        lines.extend(self.post_json_async_send_lines_net45(send_base));
+        if self.enable_retry {
+            lines.extend([
+                "                Catch ex As HttpRequestException When attempt < _maxAttempts - 1".to_string(),
+                "                    Dim delayMs As Integer = ComputeBackoffDelayMs(attempt)".to_string(),
+                "                    attempt += 1".to_string(),
+                "                    Await Task.Delay(delayMs, cancellationToken).ConfigureAwait(False)".to_string(),
+                "                End Try".to_string(),
+                "            Loop".to_string(),
+            ]);
+        }
+        lines.extend(["        End Function".to_string(), "".to_string()]);
+        if self.enable_compression {
+            lines.extend(self.generate_compression_helpers_net45());
+        }
+        if self.enable_retry {
+            lines.extend(self.generate_retry_helpers_net45());
+        }
 
         for rpc in service.unary_rpcs() {
-            lines.extend(self.generate_rpc_methods_net45(rpc, proto));
+            lines.extend(self.generate_rpc_methods_net45(service, rpc, proto));
             lines.push("".to_string());
         }
 
+        if self.streaming {
+            for rpc in service.rpcs() {
+                if rpc.is_server_streaming_only() {
+                    lines.extend(self.generate_streaming_rpc_methods_net45(service, rpc, proto));
+                    lines.push("".to_string());
+                } else if rpc.is_client_streaming_only() {
+                    lines.extend(self.generate_client_streaming_rpc_methods_net45(service, rpc, proto));
+                    lines.push("".to_string());
+                } else if rpc.is_bidi_streaming() {
+                    lines.extend(self.generate_bidi_streaming_rpc_methods_net45(service, rpc, proto));
+                    lines.push("".to_string());
+                }
+            }
+        }
+
         lines.push("    End Class".to_string());
         lines.join("\n")
     }
@@ -216,88 +915,121 @@ impl VbNetGenerator {
     /// Generate service client for .NET 4.0 HttpWebRequest mode (synchronous)
     fn generate_service_net40hwr(&self, service: &ProtoService, proto: &ProtoFile) -> String {
         let mut lines = Vec::new();
-        let client_name = format!("{}Client", service.name());
+        let client_name = format!("{}Client", self.resolve_type_name(service.name().as_str()));
 
         // Class declaration and fields
         lines.extend([
             format!("    Public Class {}", client_name),
             "        Private ReadOnly _baseUrl As String".to_string(),
-            "".to_string(),
         ]);
-
-        // Constructor
+        if self.enable_retry {
+            lines.extend([
+                "        Private ReadOnly _maxAttempts As Integer".to_string(),
+                "        Private ReadOnly _baseDelayMs As Integer".to_string(),
+                "        Private ReadOnly _maxDelayMs As Integer".to_string(),
+                "        Private ReadOnly _random As New Random()".to_string(),
+            ]);
+        }
+        if self.credential_mode == CredentialMode::BearerToken {
+            lines.push("        Private ReadOnly _tokenProvider As Func(Of String)".to_string());
+        }
+        if self.credential_mode == CredentialMode::ApiKey {
+            lines.push("        Private ReadOnly _apiKey As String".to_string());
+        }
+        lines.push("".to_string());
+
+        // Constructor. `apiKey` is a required parameter, so it must come
+        // right after `baseUrl` and before any `Optional` ones - VB.NET
+        // doesn't allow a required parameter after an optional one.
+        let mut ctor_params = "baseUrl As String".to_string();
+        if self.credential_mode == CredentialMode::ApiKey {
+            ctor_params.push_str(", apiKey As String");
+        }
+        match (self.enable_retry, self.credential_mode) {
+            (true, CredentialMode::BearerToken) => ctor_params.push_str(", Optional tokenProvider As Func(Of String) = Nothing, Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000"),
+            (true, _) => ctor_params.push_str(", Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000"),
+            (false, CredentialMode::BearerToken) => ctor_params.push_str(", Optional tokenProvider As Func(Of String) = Nothing"),
+            (false, _) => {}
+        }
         lines.extend([
-            "        Public Sub New(baseUrl As String)".to_string(),
+            format!("        Public Sub New({})", ctor_params),
             "            If String.IsNullOrWhiteSpace(baseUrl) Then Throw New ArgumentException(\"baseUrl cannot be null or empty\")".to_string(),
             "            _baseUrl = baseUrl.TrimEnd(\"/\"c)".to_string(),
-            "        End Sub".to_string(),
-            "".to_string(),
         ]);
+        if self.credential_mode == CredentialMode::BearerToken {
+            lines.push("            _tokenProvider = tokenProvider".to_string());
+        }
+        if self.credential_mode == CredentialMode::ApiKey {
+            lines.extend([
+                "            If String.IsNullOrWhiteSpace(apiKey) Then Throw New ArgumentException(\"apiKey cannot be null or empty\")".to_string(),
+                "            _apiKey = apiKey".to_string(),
+            ]);
+        }
+        if self.enable_retry {
+            lines.extend([
+                "            If maxAttempts < 1 Then Throw New ArgumentOutOfRangeException(NameOf(maxAttempts))".to_string(),
+                "            _maxAttempts = maxAttempts".to_string(),
+                "            _baseDelayMs = baseDelayMs".to_string(),
+                "            _maxDelayMs = maxDelayMs".to_string(),
+            ]);
+        }
+        lines.extend(["        End Sub".to_string(), "".to_string()]);
 
         // Shared HTTP helper (synchronous) to reduce duplication
         lines.extend([
             "        Private Function PostJson(Of TReq, TResp)(relativePath As String, request As TReq, Optional timeoutMs As Integer? = Nothing) As TResp".to_string(),
             "            If request Is Nothing Then Throw New ArgumentNullException(\"request\")".to_string(),
             "            Dim url As String = String.Format(\"{0}/{1}\", _baseUrl, relativePath.TrimStart(\"/\"c))".to_string(),
-            "            Dim json As String = JsonConvert.SerializeObject(request)".to_string(),
-            "            Dim data As Byte() = Encoding.UTF8.GetBytes(json)".to_string(),
-            "            Dim req As HttpWebRequest = CType(WebRequest.Create(url), HttpWebRequest)".to_string(),
-            "            req.Method = \"POST\"".to_string(),
-            "            req.ContentType = \"application/json\"".to_string(),
-            "            req.ContentLength = data.Length".to_string(),
-            "            If timeoutMs.HasValue Then req.Timeout = timeoutMs.Value".to_string(),
-            "            Using reqStream As Stream = req.GetRequestStream()".to_string(),
-            "                reqStream.Write(data, 0, data.Length)".to_string(),
-            "            End Using".to_string(),
-            "            Try".to_string(),
-            "                Using resp As HttpWebResponse = CType(req.GetResponse(), HttpWebResponse)".to_string(),
-            "                    Using respStream As Stream = resp.GetResponseStream()".to_string(),
-            "                        Using reader As New StreamReader(respStream, Encoding.UTF8)".to_string(),
-            "                            Dim respJson As String = reader.ReadToEnd()".to_string(),
-            "                            If String.IsNullOrWhiteSpace(respJson) Then".to_string(),
-            "                                Throw New InvalidOperationException(\"Received empty response from server\")".to_string(),
-            "                            End If".to_string(),
-            "                            Return JsonConvert.DeserializeObject(Of TResp)(respJson)".to_string(),
-            "                        End Using".to_string(),
-            "                    End Using".to_string(),
-            "                End Using".to_string(),
-            "            Catch ex As WebException".to_string(),
-            "                If TypeOf ex.Response Is HttpWebResponse Then".to_string(),
-            "                    Using errorResp As HttpWebResponse = CType(ex.Response, HttpWebResponse)".to_string(),
-            "                        Using errorStream As Stream = errorResp.GetResponseStream()".to_string(),
-            "                            If errorStream IsNot Nothing Then".to_string(),
-            "                                Using errorReader As New StreamReader(errorStream, Encoding.UTF8)".to_string(),
-            "                                    Dim errorBody As String = errorReader.ReadToEnd()".to_string(),
-            "                                    Throw New WebException($\"Request failed with status {(CInt(errorResp.StatusCode))} ({errorResp.StatusDescription}): {errorBody}\")".to_string(),
-            "                                End Using".to_string(),
-            "                            Else".to_string(),
-            "                                Throw New WebException($\"Request failed with status {(CInt(errorResp.StatusCode))} ({errorResp.StatusDescription})\")".to_string(),
-            "                            End If".to_string(),
-            "                        End Using".to_string(),
-            "                    End Using".to_string(),
-            "                Else".to_string(),
-            "                    Throw New WebException($\"Request failed: {ex.Message}\", ex)".to_string(),
-            "                End If".to_string(),
-            "            End Try".to_string(),
-            "        End Function".to_string(),
-            "".to_string(),
         ]);
+        let send_base = if self.enable_retry {
+            lines.push("            Dim attempt As Integer = 0".to_string());
+            lines.push("            Do".to_string());
+            "                "
+        } else {
+            "            "
+        };
+        lines.extend(self.post_json_send_lines_net40hwr(send_base));
+        if self.enable_retry {
+            lines.push("            Loop".to_string());
+        }
+        lines.extend(["        End Function".to_string(), "".to_string()]);
+        if self.enable_compression {
+            lines.extend(self.generate_compression_helpers_net40hwr());
+        }
+        if self.enable_retry {
+            lines.extend(self.generate_retry_helpers_net40hwr());
+        }
 
         for rpc in service.unary_rpcs() {
-            lines.extend(self.generate_rpc_methods_net40hwr(rpc, proto));
+            lines.extend(self.generate_rpc_methods_net40hwr(service, rpc, proto));
             lines.push("".to_string());
         }
 
+        if self.streaming {
+            for rpc in service.rpcs() {
+                if rpc.is_server_streaming_only() {
+                    lines.extend(self.generate_streaming_rpc_methods_net40hwr(service, rpc, proto));
+                    lines.push("".to_string());
+                } else if rpc.is_client_streaming_only() {
+                    lines.extend(self.generate_client_streaming_rpc_methods_net40hwr(service, rpc, proto));
+                    lines.push("".to_string());
+                } else if rpc.is_bidi_streaming() {
+                    lines.extend(self.generate_bidi_streaming_rpc_methods_net40hwr(service, rpc, proto));
+                    lines.push("".to_string());
+                }
+            }
+        }
+
         lines.push("    End Class".to_string());
         lines.join("\n")
     }
 
     /// Generate RPC method overloads for .NET 4.5 mode (with and without cancellation token)
-    fn generate_rpc_methods_net45(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
-        let method_name = format!("{}Async", rpc.name());
-        let input_type = rpc.input_type().to_vb_type(proto.package());
-        let output_type = rpc.output_type().to_vb_type(proto.package());
-        let relative_path = self.build_relative_path(rpc, proto);
+    fn generate_rpc_methods_net45(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = format!("{}Async", self.resolve_type_name(rpc.name().as_str()));
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
 
         let mut methods = Vec::new();
 
@@ -340,11 +1072,11 @@ impl VbNetGenerator {
     }
 
     /// Generate RPC methods for .NET 4.0 HttpWebRequest mode (synchronous)
-    fn generate_rpc_methods_net40hwr(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
-        let method_name = rpc.name().to_string();
-        let input_type = rpc.input_type().to_vb_type(proto.package());
-        let output_type = rpc.output_type().to_vb_type(proto.package());
-        let relative_path = self.build_relative_path(rpc, proto);
+    fn generate_rpc_methods_net40hwr(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = self.resolve_type_name(rpc.name().as_str());
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
 
         vec![
             // Overload without timeout
@@ -371,25 +1103,1068 @@ impl VbNetGenerator {
         ]
     }
 
-    /// Build URL template for RPC method
-    fn build_url_template(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
-        let file_stem = Path::new(proto.file_name())
-            .file_stem()
-            .unwrap_or_default()
-            .to_string_lossy();
-        let kebab_rpc = rpc.url_name();
-        format!("\"{{{}}}/{}/{}\"", "0", file_stem, kebab_rpc)
+    /// Generate a server-streaming RPC method for .NET 4.5 mode: posts the
+    /// request, then reads the NDJSON response body line by line, invoking
+    /// `onNext` for each decoded message as it arrives. Cancellation is
+    /// checked before each read, not just at the initial request, so a
+    /// long-lived stream can still be torn down promptly.
+    fn generate_streaming_rpc_methods_net45(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = format!("{}Async", self.resolve_type_name(rpc.name().as_str()));
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+
+        let mut lines = vec![
+            format!(
+                "        Public Async Function {}(request As {}, onNext As Action(Of {}), cancellationToken As CancellationToken) As Task",
+                method_name, input_type, output_type
+            ),
+            "            If request Is Nothing Then Throw New ArgumentNullException(NameOf(request))".to_string(),
+            "            If onNext Is Nothing Then Throw New ArgumentNullException(NameOf(onNext))".to_string(),
+            format!(
+                "            Dim url As String = String.Format(\"{{0}}/{{1}}\", _baseUrl, {}.TrimStart(\"/\"c))",
+                relative_path
+            ),
+            "            Dim json As String = JsonConvert.SerializeObject(request)".to_string(),
+        ];
+        lines.extend(self.request_content_lines_net45("            "));
+        lines.extend([
+            "                Using req As New HttpRequestMessage(HttpMethod.Post, url)".to_string(),
+            "                    req.Content = content".to_string(),
+        ]);
+        lines.extend(self.auth_header_lines_net45("                    "));
+        lines.extend([
+            "                    Using response As HttpResponseMessage = Await _http.SendAsync(req, HttpCompletionOption.ResponseHeadersRead, cancellationToken).ConfigureAwait(False)".to_string(),
+            "                        If Not response.IsSuccessStatusCode Then".to_string(),
+        ]);
+        lines.extend(self.read_response_body_lines_net45("body", "                            "));
+        lines.extend(self.error_throw_lines_net45("                            "));
+        lines.extend([
+            "                        End If".to_string(),
+            "                        Using respStream As Stream = Await response.Content.ReadAsStreamAsync().ConfigureAwait(False)".to_string(),
+        ]);
+        if self.enable_compression {
+            lines.extend([
+                "                            Dim decodedStream As Stream = respStream".to_string(),
+                "                            If response.Content.Headers.ContentEncoding.Contains(\"gzip\") Then decodedStream = New GZipStream(respStream, CompressionMode.Decompress)".to_string(),
+                "                            Using reader As New StreamReader(decodedStream, Encoding.UTF8)".to_string(),
+            ]);
+        } else {
+            lines.push("                            Using reader As New StreamReader(respStream, Encoding.UTF8)".to_string());
+        }
+        lines.extend([
+            "                                Do While Not reader.EndOfStream".to_string(),
+            "                                    cancellationToken.ThrowIfCancellationRequested()".to_string(),
+            "                                    Dim line As String = Await reader.ReadLineAsync().ConfigureAwait(False)".to_string(),
+            "                                    If Not String.IsNullOrWhiteSpace(line) Then".to_string(),
+            format!(
+                "                                        onNext(JsonConvert.DeserializeObject(Of {})(line))",
+                output_type
+            ),
+            "                                    End If".to_string(),
+            "                                Loop".to_string(),
+            "                            End Using".to_string(),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+        ]);
+        lines
+    }
+
+    /// Generate a server-streaming RPC method for .NET 4.0 HttpWebRequest
+    /// mode: posts the request synchronously, then reads the NDJSON
+    /// response body line by line, invoking `onNext` for each decoded
+    /// message as it arrives.
+    fn generate_streaming_rpc_methods_net40hwr(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = self.resolve_type_name(rpc.name().as_str());
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+
+        let mut lines = vec![
+            format!(
+                "        Public Sub {}(request As {}, onNext As Action(Of {}))",
+                method_name, input_type, output_type
+            ),
+            "            If request Is Nothing Then Throw New ArgumentNullException(\"request\")".to_string(),
+            "            If onNext Is Nothing Then Throw New ArgumentNullException(\"onNext\")".to_string(),
+            format!(
+                "            Dim url As String = String.Format(\"{{0}}/{{1}}\", _baseUrl, {}.TrimStart(\"/\"c))",
+                relative_path
+            ),
+            "            Dim json As String = JsonConvert.SerializeObject(request)".to_string(),
+            format!(
+                "            Dim data As Byte() = {}",
+                if self.enable_compression { "CompressGzip(Encoding.UTF8.GetBytes(json))" } else { "Encoding.UTF8.GetBytes(json)" }
+            ),
+            "            Dim req As HttpWebRequest = CType(WebRequest.Create(url), HttpWebRequest)".to_string(),
+            "            req.Method = \"POST\"".to_string(),
+            "            req.ContentType = \"application/json\"".to_string(),
+        ];
+        if self.enable_compression {
+            lines.push("            req.Headers.Add(\"Content-Encoding\", \"gzip\")".to_string());
+            lines.push(
+                "            req.AutomaticDecompression = DecompressionMethods.GZip Or DecompressionMethods.Deflate".to_string(),
+            );
+        }
+        lines.extend(self.auth_header_lines_net40hwr("            "));
+        lines.extend([
+            "            req.ContentLength = data.Length".to_string(),
+            "            Using reqStream As Stream = req.GetRequestStream()".to_string(),
+            "                reqStream.Write(data, 0, data.Length)".to_string(),
+            "            End Using".to_string(),
+            "            Try".to_string(),
+            "                Using resp As HttpWebResponse = CType(req.GetResponse(), HttpWebResponse)".to_string(),
+            "                    Using respStream As Stream = resp.GetResponseStream()".to_string(),
+            "                        Using reader As New StreamReader(respStream, Encoding.UTF8)".to_string(),
+            "                            Do While Not reader.EndOfStream".to_string(),
+            "                                Dim line As String = reader.ReadLine()".to_string(),
+            "                                If Not String.IsNullOrWhiteSpace(line) Then".to_string(),
+            format!(
+                "                                    onNext(JsonConvert.DeserializeObject(Of {})(line))",
+                output_type
+            ),
+            "                                End If".to_string(),
+            "                            Loop".to_string(),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            Catch ex As WebException".to_string(),
+            "                If TypeOf ex.Response Is HttpWebResponse Then".to_string(),
+            "                    Using errorResp As HttpWebResponse = CType(ex.Response, HttpWebResponse)".to_string(),
+            "                        Using errorStream As Stream = errorResp.GetResponseStream()".to_string(),
+            "                            If errorStream IsNot Nothing Then".to_string(),
+            "                                Using errorReader As New StreamReader(errorStream, Encoding.UTF8)".to_string(),
+            "                                    Dim errorBody As String = errorReader.ReadToEnd()".to_string(),
+        ]);
+        lines.extend(self.error_throw_lines_net40hwr_with_body("                                    "));
+        lines.extend([
+            "                                End Using".to_string(),
+            "                            Else".to_string(),
+        ]);
+        lines.extend(self.error_throw_lines_net40hwr_no_body("                                "));
+        lines.extend([
+            "                            End If".to_string(),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                Else".to_string(),
+            "                    Throw New WebException($\"Request failed: {ex.Message}\", ex)".to_string(),
+            "                End If".to_string(),
+            "            End Try".to_string(),
+            "        End Sub".to_string(),
+        ]);
+        lines
+    }
+
+    /// Generate a client-streaming RPC method for .NET 4.5 mode: there's no
+    /// true chunked-upload transport here, so each request in `requests` is
+    /// serialized as one NDJSON line and the whole sequence is buffered into
+    /// a single POST body before the (single) response is awaited.
+    fn generate_client_streaming_rpc_methods_net45(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = format!("{}Async", self.resolve_type_name(rpc.name().as_str()));
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+
+        let mut lines = vec![
+            format!(
+                "        Public Async Function {}(requests As IEnumerable(Of {}), cancellationToken As CancellationToken) As Task(Of {})",
+                method_name, input_type, output_type
+            ),
+            "            If requests Is Nothing Then Throw New ArgumentNullException(NameOf(requests))".to_string(),
+            format!(
+                "            Dim url As String = String.Format(\"{{0}}/{{1}}\", _baseUrl, {}.TrimStart(\"/\"c))",
+                relative_path
+            ),
+            "            Dim sb As New StringBuilder()".to_string(),
+            "            For Each item In requests".to_string(),
+            "                If item Is Nothing Then Throw New ArgumentNullException(NameOf(requests))".to_string(),
+            "                sb.Append(JsonConvert.SerializeObject(item))".to_string(),
+            "                sb.Append(vbLf)".to_string(),
+            "            Next".to_string(),
+            "            Dim json As String = sb.ToString()".to_string(),
+        ];
+        lines.extend(self.request_content_lines_net45("            "));
+        lines.extend([
+            "                Using req As New HttpRequestMessage(HttpMethod.Post, url)".to_string(),
+            "                    req.Content = content".to_string(),
+        ]);
+        lines.extend(self.auth_header_lines_net45("                    "));
+        lines.extend([
+            "                    Using response As HttpResponseMessage = Await _http.SendAsync(req, cancellationToken).ConfigureAwait(False)".to_string(),
+            "                        If Not response.IsSuccessStatusCode Then".to_string(),
+        ]);
+        lines.extend(self.read_response_body_lines_net45("body", "                            "));
+        lines.extend(self.error_throw_lines_net45("                            "));
+        lines.push("                        End If".to_string());
+        lines.extend(self.read_response_body_lines_net45("respJson", "                        "));
+        lines.extend([
+            format!("                        Return JsonConvert.DeserializeObject(Of {})(respJson)", output_type),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+        ]);
+        lines
+    }
+
+    /// Generate a bidirectional-streaming RPC method for .NET 4.5 mode:
+    /// combines the request buffering of
+    /// [`Self::generate_client_streaming_rpc_methods_net45`] with the NDJSON
+    /// response reading of
+    /// [`Self::generate_streaming_rpc_methods_net45`], since a true
+    /// independent-direction stream has no HTTP/1.1 request/response
+    /// mapping.
+    fn generate_bidi_streaming_rpc_methods_net45(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = format!("{}Async", self.resolve_type_name(rpc.name().as_str()));
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+
+        let mut lines = vec![
+            format!(
+                "        Public Async Function {}(requests As IEnumerable(Of {}), onNext As Action(Of {}), cancellationToken As CancellationToken) As Task",
+                method_name, input_type, output_type
+            ),
+            "            If requests Is Nothing Then Throw New ArgumentNullException(NameOf(requests))".to_string(),
+            "            If onNext Is Nothing Then Throw New ArgumentNullException(NameOf(onNext))".to_string(),
+            format!(
+                "            Dim url As String = String.Format(\"{{0}}/{{1}}\", _baseUrl, {}.TrimStart(\"/\"c))",
+                relative_path
+            ),
+            "            Dim sb As New StringBuilder()".to_string(),
+            "            For Each item In requests".to_string(),
+            "                If item Is Nothing Then Throw New ArgumentNullException(NameOf(requests))".to_string(),
+            "                sb.Append(JsonConvert.SerializeObject(item))".to_string(),
+            "                sb.Append(vbLf)".to_string(),
+            "            Next".to_string(),
+            "            Dim json As String = sb.ToString()".to_string(),
+        ];
+        lines.extend(self.request_content_lines_net45("            "));
+        lines.extend([
+            "                Using req As New HttpRequestMessage(HttpMethod.Post, url)".to_string(),
+            "                    req.Content = content".to_string(),
+        ]);
+        lines.extend(self.auth_header_lines_net45("                    "));
+        lines.extend([
+            "                    Using response As HttpResponseMessage = Await _http.SendAsync(req, HttpCompletionOption.ResponseHeadersRead, cancellationToken).ConfigureAwait(False)".to_string(),
+            "                        If Not response.IsSuccessStatusCode Then".to_string(),
+        ]);
+        lines.extend(self.read_response_body_lines_net45("body", "                            "));
+        lines.extend(self.error_throw_lines_net45("                            "));
+        lines.extend([
+            "                        End If".to_string(),
+            "                        Using respStream As Stream = Await response.Content.ReadAsStreamAsync().ConfigureAwait(False)".to_string(),
+        ]);
+        if self.enable_compression {
+            lines.extend([
+                "                            Dim decodedStream As Stream = respStream".to_string(),
+                "                            If response.Content.Headers.ContentEncoding.Contains(\"gzip\") Then decodedStream = New GZipStream(respStream, CompressionMode.Decompress)".to_string(),
+                "                            Using reader As New StreamReader(decodedStream, Encoding.UTF8)".to_string(),
+            ]);
+        } else {
+            lines.push("                            Using reader As New StreamReader(respStream, Encoding.UTF8)".to_string());
+        }
+        lines.extend([
+            "                                Do While Not reader.EndOfStream".to_string(),
+            "                                    cancellationToken.ThrowIfCancellationRequested()".to_string(),
+            "                                    Dim line As String = Await reader.ReadLineAsync().ConfigureAwait(False)".to_string(),
+            "                                    If Not String.IsNullOrWhiteSpace(line) Then".to_string(),
+            format!(
+                "                                        onNext(JsonConvert.DeserializeObject(Of {})(line))",
+                output_type
+            ),
+            "                                    End If".to_string(),
+            "                                Loop".to_string(),
+            "                            End Using".to_string(),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+        ]);
+        lines
+    }
+
+    /// Generate a client-streaming RPC method for .NET 4.0 HttpWebRequest
+    /// mode: buffers `requests` into a single NDJSON body (there's no
+    /// chunked-upload support in this legacy transport) and returns the
+    /// single decoded response, synchronously.
+    fn generate_client_streaming_rpc_methods_net40hwr(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = self.resolve_type_name(rpc.name().as_str());
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+        let relative_path = self.build_relative_path(service, rpc, proto);
+
+        let mut lines = vec![
+            format!(
+                "        Public Function {}(requests As IEnumerable(Of {})) As {}",
+                method_name, input_type, output_type
+            ),
+            "            If requests Is Nothing Then Throw New ArgumentNullException(\"requests\")".to_string(),
+            format!(
+                "            Dim url As String = String.Format(\"{{0}}/{{1}}\", _baseUrl, {}.TrimStart(\"/\"c))",
+                relative_path
+            ),
+            "            Dim sb As New StringBuilder()".to_string(),
+            "            For Each item In requests".to_string(),
+            "                If item Is Nothing Then Throw New ArgumentNullException(\"requests\")".to_string(),
+            "                sb.Append(JsonConvert.SerializeObject(item))".to_string(),
+            "                sb.Append(vbLf)".to_string(),
+            "            Next".to_string(),
+            "            Dim json As String = sb.ToString()".to_string(),
+            format!(
+                "            Dim data As Byte() = {}",
+                if self.enable_compression { "CompressGzip(Encoding.UTF8.GetBytes(json))" } else { "Encoding.UTF8.GetBytes(json)" }
+            ),
+            "            Dim req As HttpWebRequest = CType(WebRequest.Create(url), HttpWebRequest)".to_string(),
+            "            req.Method = \"POST\"".to_string(),
+            "            req.ContentType = \"application/json\"".to_string(),
+        ];
+        if self.enable_compression {
+            lines.push("            req.Headers.Add(\"Content-Encoding\", \"gzip\")".to_string());
+            lines.push(
+                "            req.AutomaticDecompression = DecompressionMethods.GZip Or DecompressionMethods.Deflate".to_string(),
+            );
+        }
+        lines.extend(self.auth_header_lines_net40hwr("            "));
+        lines.extend([
+            "            req.ContentLength = data.Length".to_string(),
+            "            Using reqStream As Stream = req.GetRequestStream()".to_string(),
+            "                reqStream.Write(data, 0, data.Length)".to_string(),
+            "            End Using".to_string(),
+            "            Try".to_string(),
+            "                Using resp As HttpWebResponse = CType(req.GetResponse(), HttpWebResponse)".to_string(),
+            "                    Using respStream As Stream = resp.GetResponseStream()".to_string(),
+            "                        Using reader As New StreamReader(respStream, Encoding.UTF8)".to_string(),
+            "                            Dim respJson As String = reader.ReadToEnd()".to_string(),
+            format!(
+                "                            Return JsonConvert.DeserializeObject(Of {})(respJson)",
+                output_type
+            ),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            Catch ex As WebException".to_string(),
+            "                If TypeOf ex.Response Is HttpWebResponse Then".to_string(),
+            "                    Using errorResp As HttpWebResponse = CType(ex.Response, HttpWebResponse)".to_string(),
+            "                        Using errorStream As Stream = errorResp.GetResponseStream()".to_string(),
+            "                            If errorStream IsNot Nothing Then".to_string(),
+            "                                Using errorReader As New StreamReader(errorStream, Encoding.UTF8)".to_string(),
+            "                                    Dim errorBody As String = errorReader.ReadToEnd()".to_string(),
+        ]);
+        lines.extend(self.error_throw_lines_net40hwr_with_body("                                    "));
+        lines.extend([
+            "                                End Using".to_string(),
+            "                            Else".to_string(),
+        ]);
+        lines.extend(self.error_throw_lines_net40hwr_no_body("                                "));
+        lines.extend([
+            "                            End If".to_string(),
+            "                        End Using".to_string(),
+            "                    End Using".to_string(),
+            "                Else".to_string(),
+            "                    Throw New WebException($\"Request failed: {ex.Message}\", ex)".to_string(),
+            "                End If".to_string(),
+            "            End Try".to_string(),
+            "        End Function".to_string(),
+        ]);
+        lines
+    }
+
+    /// Generate a bidirectional-streaming RPC stub for .NET 4.0
+    /// HttpWebRequest mode. Unlike client-streaming, there's no reasonable
+    /// buffered fallback for a bidi RPC's independently-flowing responses
+    /// on this legacy, fully-synchronous transport, so the generated method
+    /// always throws, directing callers to the .NET 4.5 target instead.
+    fn generate_bidi_streaming_rpc_methods_net40hwr(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> Vec<String> {
+        let method_name = self.resolve_type_name(rpc.name().as_str());
+        let input_type = self.apply_type_overrides(&rpc.input_type().to_vb_type(proto.package()));
+        let output_type = self.apply_type_overrides(&rpc.output_type().to_vb_type(proto.package()));
+
+        vec![
+            format!(
+                "        Public Sub {}(requests As IEnumerable(Of {}), onNext As Action(Of {}))",
+                method_name, input_type, output_type
+            ),
+            format!(
+                "            Throw New NotSupportedException(\"{}.{} is a bidirectional-streaming RPC, which the .NET 4.0 HttpWebRequest target cannot support; generate with --compat-mode Net45 instead.\")",
+                service.name(),
+                rpc.name()
+            ),
+            "        End Sub".to_string(),
+        ]
+    }
+
+    /// VB lines attaching the configured credential to an already-built
+    /// `req As HttpWebRequest`, assumed in scope. Unlike the .NET 4.5 side,
+    /// there's no shared `_http.DefaultRequestHeaders` to set a static API
+    /// key on once — each call builds its own `HttpWebRequest` — so both
+    /// `CredentialMode` variants attach their header here, per call.
+    fn auth_header_lines_net40hwr(&self, indent: &str) -> Vec<String> {
+        match self.credential_mode {
+            CredentialMode::None => vec![],
+            CredentialMode::BearerToken => vec![format!(
+                "{}If _tokenProvider IsNot Nothing Then req.Headers(\"Authorization\") = \"Bearer \" & _tokenProvider()",
+                indent
+            )],
+            CredentialMode::ApiKey => vec![format!("{}req.Headers.Add(\"X-Api-Key\", _apiKey)", indent)],
+        }
+    }
+
+    /// Build URL template for RPC method
+    fn build_url_template(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
+        let file_stem = Path::new(proto.file_name())
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let kebab_rpc = rpc.url_name();
+        format!("\"{{{}}}/{}/{}\"", "0", file_stem, kebab_rpc)
+    }
+
+    /// VB lines attaching the bearer token to an already-constructed `req As
+    /// HttpRequestMessage`, for the streaming methods that build their own
+    /// request rather than going through [`Self::post_response_lines_net45`].
+    /// A no-op outside [`CredentialMode::BearerToken`] (an API key, being
+    /// static, is already on `_http.DefaultRequestHeaders`).
+    fn auth_header_lines_net45(&self, indent: &str) -> Vec<String> {
+        match self.credential_mode {
+            CredentialMode::BearerToken => vec![
+                format!("{}If _tokenProvider IsNot Nothing Then", indent),
+                format!("{}    Dim bearerToken As String = Await _tokenProvider().ConfigureAwait(False)", indent),
+                format!("{}    req.Headers.Authorization = New AuthenticationHeaderValue(\"Bearer\", bearerToken)", indent),
+                format!("{}End If", indent),
+            ],
+            CredentialMode::None | CredentialMode::ApiKey => vec![],
+        }
+    }
+
+    /// VB lines producing `Dim {response_var} As HttpResponseMessage =
+    /// ...`, honoring [`Self::credential_mode`]. `CredentialMode::None` and
+    /// `CredentialMode::ApiKey` post directly via `HttpClient.PostAsync` —
+    /// the API key, being static, is already on `_http.DefaultRequestHeaders`
+    /// from the constructor. `CredentialMode::BearerToken` instead builds an
+    /// `HttpRequestMessage` so the per-call token (awaited from
+    /// `_tokenProvider`) can be attached to just that one request's headers.
+    fn post_response_lines_net45(&self, response_var: &str, token_expr: &str, indent: &str) -> Vec<String> {
+        match self.credential_mode {
+            CredentialMode::BearerToken => vec![
+                format!("{}Dim req As New HttpRequestMessage(HttpMethod.Post, url) With {{.Content = content}}", indent),
+                format!("{}If _tokenProvider IsNot Nothing Then", indent),
+                format!("{}    Dim bearerToken As String = Await _tokenProvider().ConfigureAwait(False)", indent),
+                format!("{}    req.Headers.Authorization = New AuthenticationHeaderValue(\"Bearer\", bearerToken)", indent),
+                format!("{}End If", indent),
+                format!(
+                    "{}Dim {} As HttpResponseMessage = Await _http.SendAsync(req, {}).ConfigureAwait(False)",
+                    indent, response_var, token_expr
+                ),
+            ],
+            CredentialMode::None | CredentialMode::ApiKey => vec![format!(
+                "{}Dim {} As HttpResponseMessage = Await _http.PostAsync(url, content, {}).ConfigureAwait(False)",
+                indent, response_var, token_expr
+            )],
+        }
+    }
+
+    /// VB lines for `PostJsonAsync`'s core send/response logic: build the
+    /// request content, send it honoring `timeoutMs`, and on a non-success
+    /// response either retry (when [`Self::enable_retry`] allows it, via
+    /// [`Self::retry_on_status_lines_net45`]) or throw. `base` is the
+    /// indentation of the `Using content As New ...` line produced by
+    /// [`Self::request_content_lines_net45`]; everything nested beneath it
+    /// is indented four spaces per level from there.
+    fn post_json_async_send_lines_net45(&self, base: &str) -> Vec<String> {
+        let lvl1 = format!("{}    ", base);
+        let lvl2 = format!("{}    ", lvl1);
+        let lvl3 = format!("{}    ", lvl2);
+        let lvl4 = format!("{}    ", lvl3);
+        let lvl5 = format!("{}    ", lvl4);
+
+        let mut lines = self.request_content_lines_net45(base);
+        lines.extend([
+            format!("{}If timeoutMs.HasValue Then", lvl1),
+            format!("{}Using timeoutCts As New CancellationTokenSource(timeoutMs.Value)", lvl2),
+            format!(
+                "{}Using combined As CancellationTokenSource = CancellationTokenSource.CreateLinkedTokenSource(cancellationToken, timeoutCts.Token)",
+                lvl3
+            ),
+        ]);
+        lines.extend(self.post_response_lines_net45("response", "combined.Token", &lvl4));
+        lines.push(format!("{}If Not response.IsSuccessStatusCode Then", lvl4));
+        if self.enable_retry {
+            lines.extend(self.retry_on_status_lines_net45(&lvl5));
+        }
+        lines.extend(self.read_response_body_lines_net45("body", &lvl5));
+        lines.extend(self.error_throw_lines_net45(&lvl5));
+        lines.push(format!("{}End If", lvl4));
+        lines.extend(self.success_response_lines_net45(&lvl4));
+        lines.extend([
+            format!("{}End Using", lvl3),
+            format!("{}End Using", lvl2),
+            format!("{}Else", lvl1),
+        ]);
+        lines.extend(self.post_response_lines_net45("response", "cancellationToken", &lvl2));
+        lines.push(format!("{}If Not response.IsSuccessStatusCode Then", lvl2));
+        if self.enable_retry {
+            lines.extend(self.retry_on_status_lines_net45(&lvl3));
+        }
+        lines.extend(self.read_response_body_lines_net45("body", &lvl3));
+        lines.extend(self.error_throw_lines_net45(&lvl3));
+        lines.push(format!("{}End If", lvl2));
+        lines.extend(self.success_response_lines_net45(&lvl2));
+        lines.push(format!("{}End If", lvl1));
+        lines.push(format!("{}End Using", base));
+        lines
+    }
+
+    /// VB lines checked right after `If Not response.IsSuccessStatusCode
+    /// Then`: on a retryable status (429/502/503/504) with attempts still
+    /// remaining, sleep off a `Retry-After`-honoring or exponential-backoff
+    /// delay and `Continue Do` back to the top of `PostJsonAsync`'s retry
+    /// loop instead of falling through to the error body read and throw.
+    fn retry_on_status_lines_net45(&self, indent: &str) -> Vec<String> {
+        vec![
+            format!("{}If IsRetryableStatusCode(CInt(response.StatusCode)) AndAlso attempt < _maxAttempts - 1 Then", indent),
+            format!(
+                "{}    Dim delayMs As Integer = GetRetryAfterDelayMs(response).GetValueOrDefault(ComputeBackoffDelayMs(attempt))",
+                indent
+            ),
+            format!("{}    attempt += 1", indent),
+            format!("{}    Await Task.Delay(delayMs, cancellationToken).ConfigureAwait(False)", indent),
+            format!("{}    Continue Do", indent),
+            format!("{}End If", indent),
+        ]
+    }
+
+    /// `IsRetryableStatusCode`/`ComputeBackoffDelayMs`/`GetRetryAfterDelayMs`
+    /// helpers shared by a .NET 4.5 client's `PostJsonAsync` whenever
+    /// [`Self::enable_retry`] is set. Backoff is `min(maxDelay, base *
+    /// 2^attempt)` plus up to 25% jitter; `Retry-After` (when the server
+    /// sends a delta-seconds form) takes priority over the computed delay.
+    fn generate_retry_helpers_net45(&self) -> Vec<String> {
+        vec![
+            "        Private Shared Function IsRetryableStatusCode(statusCode As Integer) As Boolean".to_string(),
+            "            Return statusCode = 429 OrElse statusCode = 502 OrElse statusCode = 503 OrElse statusCode = 504".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            "        Private Function ComputeBackoffDelayMs(attempt As Integer) As Integer".to_string(),
+            "            Dim exponential As Double = _baseDelayMs * Math.Pow(2, attempt)".to_string(),
+            "            Dim capped As Double = Math.Min(CDbl(_maxDelayMs), exponential)".to_string(),
+            "            Return CInt(capped + capped * _random.NextDouble() * 0.25)".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            "        Private Function GetRetryAfterDelayMs(response As HttpResponseMessage) As Integer?".to_string(),
+            "            Dim retryAfter = response.Headers.RetryAfter".to_string(),
+            "            If retryAfter IsNot Nothing AndAlso retryAfter.Delta.HasValue Then".to_string(),
+            "                Return CInt(retryAfter.Delta.Value.TotalMilliseconds)".to_string(),
+            "            End If".to_string(),
+            "            Return Nothing".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+        ]
+    }
+
+    /// VB lines opening the `Using content As ...` block that wraps the
+    /// serialized request body, for .NET 4.5.
+    ///
+    /// For [`SerializationFormat::Json`]: when [`Self::enable_compression`] is
+    /// set, `json` is gzipped into a `ByteArrayContent` with an explicit
+    /// `Content-Type`/`Content-Encoding`; otherwise it's the plain
+    /// `StringContent` this generator has always used. Assumes a `json As
+    /// String` variable is already in scope.
+    ///
+    /// For [`SerializationFormat::Protobuf`]: `request` is serialized
+    /// directly via `ProtoBuf.Serializer` into a `ByteArrayContent` sent as
+    /// `application/protobuf`, gzipped first when compression is also on.
+    /// Assumes a `request As TReq` variable is already in scope.
+    ///
+    /// When [`Self::compression_threshold_bytes`] is positive, gzipping (and
+    /// the `Content-Encoding` header it implies) is further gated on a
+    /// runtime `compressRequest` check against the serialized body's size,
+    /// so small bodies skip the compression overhead entirely.
+    ///
+    /// Either way a matching `End Using` closes the block.
+    fn request_content_lines_net45(&self, indent: &str) -> Vec<String> {
+        let threshold = self.compression_threshold_bytes;
+        match self.serialization_format {
+            SerializationFormat::Protobuf => {
+                let mut lines = vec![
+                    format!("{}Dim requestBytes As Byte()", indent),
+                    format!("{}Using reqStream As New MemoryStream()", indent),
+                    format!("{}    ProtoBuf.Serializer.Serialize(Of TReq)(reqStream, request)", indent),
+                    format!("{}    requestBytes = reqStream.ToArray()", indent),
+                    format!("{}End Using", indent),
+                ];
+                if self.enable_compression && threshold > 0 {
+                    lines.push(format!("{}Dim compressRequest As Boolean = requestBytes.Length > {}", indent, threshold));
+                    lines.push(format!("{}If compressRequest Then requestBytes = CompressGzip(requestBytes)", indent));
+                } else if self.enable_compression {
+                    lines.push(format!("{}requestBytes = CompressGzip(requestBytes)", indent));
+                }
+                lines.push(format!("{}Using content As New ByteArrayContent(requestBytes)", indent));
+                lines.push(format!(
+                    "{}    content.Headers.ContentType = New MediaTypeHeaderValue(\"application/protobuf\")",
+                    indent
+                ));
+                if self.enable_compression && threshold > 0 {
+                    lines.push(format!("{}    If compressRequest Then content.Headers.ContentEncoding.Add(\"gzip\")", indent));
+                } else if self.enable_compression {
+                    lines.push(format!("{}    content.Headers.ContentEncoding.Add(\"gzip\")", indent));
+                }
+                lines
+            }
+            SerializationFormat::Json if self.enable_compression && threshold > 0 => vec![
+                format!("{}Dim requestBytes As Byte() = Encoding.UTF8.GetBytes(json)", indent),
+                format!("{}Dim compressRequest As Boolean = requestBytes.Length > {}", indent, threshold),
+                format!("{}If compressRequest Then requestBytes = CompressGzip(requestBytes)", indent),
+                format!("{}Using content As New ByteArrayContent(requestBytes)", indent),
+                format!("{}    content.Headers.ContentType = New MediaTypeHeaderValue(\"application/json\")", indent),
+                format!("{}    If compressRequest Then content.Headers.ContentEncoding.Add(\"gzip\")", indent),
+            ],
+            SerializationFormat::Json if self.enable_compression => vec![
+                format!("{}Dim requestBytes As Byte() = CompressGzip(Encoding.UTF8.GetBytes(json))", indent),
+                format!("{}Using content As New ByteArrayContent(requestBytes)", indent),
+                format!("{}    content.Headers.ContentType = New MediaTypeHeaderValue(\"application/json\")", indent),
+                format!("{}    content.Headers.ContentEncoding.Add(\"gzip\")", indent),
+            ],
+            SerializationFormat::Json => {
+                vec![format!("{}Using content As New StringContent(json, Encoding.UTF8, \"application/json\")", indent)]
+            }
+        }
+    }
+
+    /// VB lines reading an `HttpResponseMessage` into the final `Return`
+    /// statement of `PostJsonAsync`, for .NET 4.5.
+    ///
+    /// For [`SerializationFormat::Json`]: reads the body as a string (via
+    /// [`Self::read_response_body_lines_net45`]) and `JsonConvert`-deserializes
+    /// it. For [`SerializationFormat::Protobuf`]: reads the body as bytes,
+    /// transparently gunzipping when [`Self::enable_compression`] is set, and
+    /// deserializes via `ProtoBuf.Serializer`. Assumes a `response As
+    /// HttpResponseMessage` variable is already in scope.
+    fn success_response_lines_net45(&self, indent: &str) -> Vec<String> {
+        match self.serialization_format {
+            SerializationFormat::Protobuf => {
+                let mut lines = vec![format!(
+                    "{}Dim responseBytes As Byte() = Await response.Content.ReadAsByteArrayAsync().ConfigureAwait(False)",
+                    indent
+                )];
+                if self.enable_compression {
+                    lines.push(format!(
+                        "{}If response.Content.Headers.ContentEncoding.Contains(\"gzip\") Then responseBytes = DecompressGzip(responseBytes)",
+                        indent
+                    ));
+                }
+                lines.extend([
+                    format!("{}If responseBytes.Length = 0 Then", indent),
+                    format!("{}    Throw New InvalidOperationException(\"Received empty response from server\")", indent),
+                    format!("{}End If", indent),
+                    format!("{}Using respStream As New MemoryStream(responseBytes)", indent),
+                    format!("{}    Return ProtoBuf.Serializer.Deserialize(Of TResp)(respStream)", indent),
+                    format!("{}End Using", indent),
+                ]);
+                lines
+            }
+            SerializationFormat::Json => {
+                let mut lines = self.read_response_body_lines_net45("respJson", indent);
+                lines.extend([
+                    format!("{}If String.IsNullOrWhiteSpace(respJson) Then", indent),
+                    format!("{}    Throw New InvalidOperationException(\"Received empty response from server\")", indent),
+                    format!("{}End If", indent),
+                    format!("{}Return JsonConvert.DeserializeObject(Of TResp)(respJson)", indent),
+                ]);
+                lines
+            }
+        }
+    }
+
+    /// VB lines reading an `HttpResponseMessage`'s body into a `{var_name} As
+    /// String` variable, for .NET 4.5. When [`Self::enable_compression`] is
+    /// set, the body is read as bytes and transparently gunzipped if the
+    /// response carries `Content-Encoding: gzip`; otherwise it's read
+    /// directly as a string. Assumes a `response As HttpResponseMessage`
+    /// variable is already in scope.
+    fn read_response_body_lines_net45(&self, var_name: &str, indent: &str) -> Vec<String> {
+        if self.enable_compression {
+            vec![
+                format!(
+                    "{}Dim {}Bytes As Byte() = Await response.Content.ReadAsByteArrayAsync().ConfigureAwait(False)",
+                    indent, var_name
+                ),
+                format!(
+                    "{}If response.Content.Headers.ContentEncoding.Contains(\"gzip\") Then {}Bytes = DecompressGzip({}Bytes)",
+                    indent, var_name, var_name
+                ),
+                format!("{}Dim {} As String = Encoding.UTF8.GetString({}Bytes)", indent, var_name, var_name),
+            ]
+        } else {
+            vec![format!(
+                "{}Dim {} As String = Await response.Content.ReadAsStringAsync().ConfigureAwait(False)",
+                indent, var_name
+            )]
+        }
+    }
+
+    /// `CompressGzip`/`DecompressGzip` helpers shared by a .NET 4.5 client's
+    /// `PostJsonAsync` (and, when `--streaming` is also on, its streaming
+    /// methods) whenever [`Self::enable_compression`] is set.
+    fn generate_compression_helpers_net45(&self) -> Vec<String> {
+        vec![
+            "        Private Shared Function CompressGzip(data As Byte()) As Byte()".to_string(),
+            "            Using output As New MemoryStream()".to_string(),
+            "                Using gzip As New GZipStream(output, CompressionMode.Compress, True)".to_string(),
+            "                    gzip.Write(data, 0, data.Length)".to_string(),
+            "                End Using".to_string(),
+            "                Return output.ToArray()".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            "        Private Shared Function DecompressGzip(data As Byte()) As Byte()".to_string(),
+            "            Using input As New MemoryStream(data)".to_string(),
+            "                Using gzip As New GZipStream(input, CompressionMode.Decompress)".to_string(),
+            "                    Using output As New MemoryStream()".to_string(),
+            "                        gzip.CopyTo(output)".to_string(),
+            "                        Return output.ToArray()".to_string(),
+            "                    End Using".to_string(),
+            "                End Using".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+        ]
+    }
+
+    /// `CompressGzip` helper shared by a .NET 4.0 HWR client's `PostJson`
+    /// (and streaming methods) whenever [`Self::enable_compression`] is
+    /// set. Unlike the .NET 4.5 side, there's no matching `DecompressGzip`
+    /// here — `HttpWebRequest.AutomaticDecompression` already ungzips
+    /// responses transparently before `GetResponseStream()` sees them.
+    fn generate_compression_helpers_net40hwr(&self) -> Vec<String> {
+        vec![
+            "        Private Shared Function CompressGzip(data As Byte()) As Byte()".to_string(),
+            "            Using output As New MemoryStream()".to_string(),
+            "                Using gzip As New GZipStream(output, CompressionMode.Compress, True)".to_string(),
+            "                    gzip.Write(data, 0, data.Length)".to_string(),
+            "                End Using".to_string(),
+            "                Return output.ToArray()".to_string(),
+            "            End Using".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+        ]
+    }
+
+    /// VB lines for `PostJson`'s core send/response logic: serialize and
+    /// send the request, and on failure either retry (when
+    /// [`Self::enable_retry`] allows it) or throw, exactly as the
+    /// generator has always done otherwise. `base` is the indentation of
+    /// the `Dim req As HttpWebRequest = ...` line; everything nested
+    /// beneath it is indented four spaces per level from there.
+    fn post_json_send_lines_net40hwr(&self, base: &str) -> Vec<String> {
+        let lvl1 = format!("{}    ", base);
+        let lvl2 = format!("{}    ", lvl1);
+        let lvl3 = format!("{}    ", lvl2);
+        let lvl4 = format!("{}    ", lvl3);
+        let lvl5 = format!("{}    ", lvl4);
+        let lvl6 = format!("{}    ", lvl5);
+
+        let mut lines = self.request_serialize_lines_net40hwr(base);
+        lines.extend([
+            format!("{}Dim req As HttpWebRequest = CType(WebRequest.Create(url), HttpWebRequest)", base),
+            format!("{}req.Method = \"POST\"", base),
+            format!(
+                "{}req.ContentType = \"{}\"",
+                base,
+                if self.serialization_format == SerializationFormat::Protobuf { "application/protobuf" } else { "application/json" }
+            ),
+        ]);
+        if self.wire_protocol == WireProtocol::Connect {
+            lines.push(format!("{}req.Headers.Add(\"Connect-Protocol-Version\", \"1\")", base));
+        }
+        if self.enable_compression {
+            if self.compression_threshold_bytes > 0 {
+                lines.push(format!("{}If compressRequest Then req.Headers.Add(\"Content-Encoding\", \"gzip\")", base));
+            } else {
+                lines.push(format!("{}req.Headers.Add(\"Content-Encoding\", \"gzip\")", base));
+            }
+            lines.push(format!("{}req.AutomaticDecompression = DecompressionMethods.GZip Or DecompressionMethods.Deflate", base));
+        }
+        lines.extend(self.auth_header_lines_net40hwr(base));
+        lines.extend([
+            format!("{}req.ContentLength = data.Length", base),
+            format!("{}If timeoutMs.HasValue Then req.Timeout = timeoutMs.Value", base),
+            format!("{}Using reqStream As Stream = req.GetRequestStream()", base),
+            format!("{}reqStream.Write(data, 0, data.Length)", lvl1),
+            format!("{}End Using", base),
+            format!("{}Try", base),
+            format!("{}Using resp As HttpWebResponse = CType(req.GetResponse(), HttpWebResponse)", lvl1),
+        ]);
+        lines.extend(self.success_response_lines_net40hwr(&lvl2));
+        lines.extend([
+            format!("{}End Using", lvl1),
+            format!("{}Catch ex As WebException", base),
+            format!("{}If TypeOf ex.Response Is HttpWebResponse Then", lvl1),
+            format!("{}Using errorResp As HttpWebResponse = CType(ex.Response, HttpWebResponse)", lvl2),
+        ]);
+        if self.enable_retry {
+            lines.extend(self.retry_on_status_lines_net40hwr(&lvl3));
+        }
+        lines.extend([
+            format!("{}Using errorStream As Stream = errorResp.GetResponseStream()", lvl3),
+            format!("{}If errorStream IsNot Nothing Then", lvl4),
+            format!("{}Using errorReader As New StreamReader(errorStream, Encoding.UTF8)", lvl5),
+            format!("{}Dim errorBody As String = errorReader.ReadToEnd()", lvl6),
+        ]);
+        lines.extend(self.error_throw_lines_net40hwr_with_body(&lvl6));
+        lines.extend([format!("{}End Using", lvl5), format!("{}Else", lvl4)]);
+        lines.extend(self.error_throw_lines_net40hwr_no_body(&lvl5));
+        lines.extend([
+            format!("{}End If", lvl4),
+            format!("{}End Using", lvl3),
+            format!("{}End Using", lvl2),
+            format!("{}Else", lvl1),
+        ]);
+        if self.enable_retry {
+            lines.extend([
+                format!("{}If attempt < _maxAttempts - 1 Then", lvl2),
+                format!("{}    Dim delayMs As Integer = ComputeBackoffDelayMs(attempt)", lvl2),
+                format!("{}    attempt += 1", lvl2),
+                format!("{}    Thread.Sleep(delayMs)", lvl2),
+                format!("{}    Continue Do", lvl2),
+                format!("{}Else", lvl2),
+                format!("{}    Throw New WebException($\"Request failed: {{ex.Message}}\", ex)", lvl2),
+                format!("{}End If", lvl2),
+            ]);
+        } else {
+            lines.push(format!("{}Throw New WebException($\"Request failed: {{ex.Message}}\", ex)", lvl2));
+        }
+        lines.extend([format!("{}End If", lvl1), format!("{}End Try", base)]);
+        lines
+    }
+
+    /// VB lines checked right after opening `Using errorResp As
+    /// HttpWebResponse = ...`: on a retryable status (429/502/503/504)
+    /// with attempts still remaining, sleep off a `Retry-After`-honoring or
+    /// exponential-backoff delay and `Continue Do` back to the top of
+    /// `PostJson`'s retry loop instead of falling through to the error body
+    /// read and throw.
+    fn retry_on_status_lines_net40hwr(&self, indent: &str) -> Vec<String> {
+        vec![
+            format!("{}If IsRetryableStatusCode(CInt(errorResp.StatusCode)) AndAlso attempt < _maxAttempts - 1 Then", indent),
+            format!(
+                "{}    Dim delayMs As Integer = GetRetryAfterDelayMs(errorResp).GetValueOrDefault(ComputeBackoffDelayMs(attempt))",
+                indent
+            ),
+            format!("{}    attempt += 1", indent),
+            format!("{}    Thread.Sleep(delayMs)", indent),
+            format!("{}    Continue Do", indent),
+            format!("{}End If", indent),
+        ]
+    }
+
+    /// `IsRetryableStatusCode`/`ComputeBackoffDelayMs`/`GetRetryAfterDelayMs`
+    /// helpers shared by a .NET 4.0 HWR client's `PostJson` whenever
+    /// [`Self::enable_retry`] is set. Mirrors
+    /// [`Self::generate_retry_helpers_net45`], except `GetRetryAfterDelayMs`
+    /// reads the raw `Retry-After` response header (seconds form only —
+    /// HTTP-date `Retry-After` isn't retried) since `HttpWebResponse`
+    /// doesn't parse it the way `HttpResponseMessage` does.
+    fn generate_retry_helpers_net40hwr(&self) -> Vec<String> {
+        vec![
+            "        Private Shared Function IsRetryableStatusCode(statusCode As Integer) As Boolean".to_string(),
+            "            Return statusCode = 429 OrElse statusCode = 502 OrElse statusCode = 503 OrElse statusCode = 504".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            "        Private Function ComputeBackoffDelayMs(attempt As Integer) As Integer".to_string(),
+            "            Dim exponential As Double = _baseDelayMs * Math.Pow(2, attempt)".to_string(),
+            "            Dim capped As Double = Math.Min(CDbl(_maxDelayMs), exponential)".to_string(),
+            "            Return CInt(capped + capped * _random.NextDouble() * 0.25)".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+            "        Private Function GetRetryAfterDelayMs(errorResp As HttpWebResponse) As Integer?".to_string(),
+            "            Dim value As String = errorResp.Headers(\"Retry-After\")".to_string(),
+            "            Dim seconds As Integer".to_string(),
+            "            If Not String.IsNullOrEmpty(value) AndAlso Integer.TryParse(value, seconds) Then".to_string(),
+            "                Return seconds * 1000".to_string(),
+            "            End If".to_string(),
+            "            Return Nothing".to_string(),
+            "        End Function".to_string(),
+            "".to_string(),
+        ]
+    }
+
+    /// VB lines producing `PostJson`'s `data As Byte()` request payload, for
+    /// .NET 4.0 HWR. For [`SerializationFormat::Json`], serializes `request`
+    /// via `JsonConvert` into a `json As String` then UTF-8 encodes it;
+    /// for [`SerializationFormat::Protobuf`], serializes it directly via
+    /// `ProtoBuf.Serializer` into a byte array. Either way, gzips the result
+    /// first when [`Self::enable_compression`] is set; when
+    /// [`Self::compression_threshold_bytes`] is also positive, gzipping is
+    /// further gated on a runtime `compressRequest` check (referenced later
+    /// by [`Self::post_json_send_lines_net40hwr`] to decide the
+    /// `Content-Encoding` header) against the payload's size. Assumes a
+    /// `request As TReq` variable is already in scope.
+    fn request_serialize_lines_net40hwr(&self, indent: &str) -> Vec<String> {
+        let threshold = self.compression_threshold_bytes;
+        match self.serialization_format {
+            SerializationFormat::Protobuf => {
+                let mut lines = vec![
+                    format!("{}Dim data As Byte()", indent),
+                    format!("{}Using reqStream As New MemoryStream()", indent),
+                    format!("{}    ProtoBuf.Serializer.Serialize(Of TReq)(reqStream, request)", indent),
+                    format!("{}    data = reqStream.ToArray()", indent),
+                    format!("{}End Using", indent),
+                ];
+                if self.enable_compression && threshold > 0 {
+                    lines.push(format!("{}Dim compressRequest As Boolean = data.Length > {}", indent, threshold));
+                    lines.push(format!("{}If compressRequest Then data = CompressGzip(data)", indent));
+                } else if self.enable_compression {
+                    lines.push(format!("{}data = CompressGzip(data)", indent));
+                }
+                lines
+            }
+            SerializationFormat::Json if self.enable_compression && threshold > 0 => vec![
+                format!("{}Dim json As String = JsonConvert.SerializeObject(request)", indent),
+                format!("{}Dim data As Byte() = Encoding.UTF8.GetBytes(json)", indent),
+                format!("{}Dim compressRequest As Boolean = data.Length > {}", indent, threshold),
+                format!("{}If compressRequest Then data = CompressGzip(data)", indent),
+            ],
+            SerializationFormat::Json => vec![
+                format!("{}Dim json As String = JsonConvert.SerializeObject(request)", indent),
+                format!(
+                    "{}Dim data As Byte() = {}",
+                    indent,
+                    if self.enable_compression { "CompressGzip(Encoding.UTF8.GetBytes(json))" } else { "Encoding.UTF8.GetBytes(json)" }
+                ),
+            ],
+        }
+    }
+
+    /// VB lines reading a successful `HttpWebResponse`'s body and returning
+    /// the deserialized `TResp`, for .NET 4.0 HWR. Opens (and closes) its own
+    /// `Using respStream As Stream = resp.GetResponseStream()` block, so
+    /// `resp As HttpWebResponse` must already be in scope. For
+    /// [`SerializationFormat::Json`], reads the body as text and
+    /// `JsonConvert`-deserializes it; for [`SerializationFormat::Protobuf`],
+    /// reads it as bytes (`AutomaticDecompression` already ungzips a
+    /// compressed response before this point) and deserializes via
+    /// `ProtoBuf.Serializer`.
+    fn success_response_lines_net40hwr(&self, indent: &str) -> Vec<String> {
+        match self.serialization_format {
+            SerializationFormat::Protobuf => vec![
+                format!("{}Using respStream As Stream = resp.GetResponseStream()", indent),
+                format!("{}    Using responseBuffer As New MemoryStream()", indent),
+                format!("{}        respStream.CopyTo(responseBuffer)", indent),
+                format!("{}        Dim responseBytes As Byte() = responseBuffer.ToArray()", indent),
+                format!("{}        If responseBytes.Length = 0 Then", indent),
+                format!("{}            Throw New InvalidOperationException(\"Received empty response from server\")", indent),
+                format!("{}        End If", indent),
+                format!("{}        Using protoStream As New MemoryStream(responseBytes)", indent),
+                format!("{}            Return ProtoBuf.Serializer.Deserialize(Of TResp)(protoStream)", indent),
+                format!("{}        End Using", indent),
+                format!("{}    End Using", indent),
+                format!("{}End Using", indent),
+            ],
+            SerializationFormat::Json => vec![
+                format!("{}Using respStream As Stream = resp.GetResponseStream()", indent),
+                format!("{}    Using reader As New StreamReader(respStream, Encoding.UTF8)", indent),
+                format!("{}        Dim respJson As String = reader.ReadToEnd()", indent),
+                format!("{}        If String.IsNullOrWhiteSpace(respJson) Then", indent),
+                format!("{}            Throw New InvalidOperationException(\"Received empty response from server\")", indent),
+                format!("{}        End If", indent),
+                format!("{}        Return JsonConvert.DeserializeObject(Of TResp)(respJson)", indent),
+                format!("{}    End Using", indent),
+                format!("{}End Using", indent),
+            ],
+        }
+    }
+
+    /// The `<package>.<Service>` name Twirp and Connect both route on,
+    /// falling back to the bare service name when the proto has no package.
+    fn qualified_service_name(&self, service: &ProtoService, proto: &ProtoFile) -> String {
+        match proto.package() {
+            Some(package) => format!("{}.{}", package.as_str(), service.name()),
+            None => service.name().to_string(),
+        }
+    }
+
+    /// Build relative path string for RPC method (leading slash). In
+    /// [`WireProtocol::Twirp`] mode this is the Twirp routing convention,
+    /// `/twirp/<package>.<Service>/<Method>`; in [`WireProtocol::Connect`]
+    /// mode it's Connect's unary route, `/<package>.<Service>/<Method>`;
+    /// both use the proto package and the RPC's original (PascalCase) name.
+    /// Otherwise it's this generator's own ad-hoc
+    /// `/{file_stem}/{kebab-rpc}/{version}` layout.
+    fn build_relative_path(&self, service: &ProtoService, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
+        match self.wire_protocol {
+            WireProtocol::Twirp => {
+                format!("\"/twirp/{}/{}\"", self.qualified_service_name(service, proto), rpc.name())
+            }
+            WireProtocol::Connect => {
+                format!("\"/{}/{}\"", self.qualified_service_name(service, proto), rpc.name())
+            }
+            WireProtocol::Legacy => {
+                let file_stem = Path::new(proto.file_name())
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy();
+                let (base_rpc_name, version_seg) = self.split_rpc_name_and_version(rpc.name().as_str());
+                let kebab_rpc = crate::types::to_kebab_case(&base_rpc_name);
+                format!("\"/{}/{}/{}\"", file_stem, kebab_rpc, version_seg)
+            }
+        }
+    }
+
+    /// VB lines throwing on a non-2xx .NET 4.5 `HttpResponseMessage`,
+    /// assuming a `body As String` variable is already in scope. Twirp mode
+    /// parses the Twirp JSON error envelope into a [`Self::generate_twirp_exception_class`]
+    /// instance instead of the generic `HttpRequestException`; Connect mode
+    /// does the same via [`Self::generate_connect_error_class`].
+    fn error_throw_lines_net45(&self, indent: &str) -> Vec<String> {
+        match self.wire_protocol {
+            WireProtocol::Legacy => vec![format!(
+                "{}Throw New HttpRequestException($\"Request failed with status {{(CInt(response.StatusCode))}} ({{response.ReasonPhrase}}): {{body}}\")",
+                indent
+            )],
+            WireProtocol::Twirp => vec![format!("{}Throw TwirpException.FromJson(body)", indent)],
+            WireProtocol::Connect => vec![format!("{}Throw ConnectError.FromJson(body)", indent)],
+        }
     }
 
-    /// Build relative path string for RPC method (leading slash)
-    fn build_relative_path(&self, rpc: &ProtoRpc, proto: &ProtoFile) -> String {
-        let file_stem = Path::new(proto.file_name())
-            .file_stem()
-            .unwrap_or_default()
-            .to_string_lossy();
-        let (base_rpc_name, version_seg) = self.split_rpc_name_and_version(rpc.name().as_str());
-        let kebab_rpc = crate::types::to_kebab_case(&base_rpc_name);
-        format!("\"/{}/{}/{}\"", file_stem, kebab_rpc, version_seg)
+    /// VB lines throwing on a non-2xx .NET 4.0 `HttpWebResponse` that has an
+    /// error body, assuming `errorResp As HttpWebResponse` and
+    /// `errorBody As String` are already in scope.
+    fn error_throw_lines_net40hwr_with_body(&self, indent: &str) -> Vec<String> {
+        match self.wire_protocol {
+            WireProtocol::Legacy => vec![format!(
+                "{}Throw New WebException($\"Request failed with status {{(CInt(errorResp.StatusCode))}} ({{errorResp.StatusDescription}}): {{errorBody}}\")",
+                indent
+            )],
+            WireProtocol::Twirp => vec![format!("{}Throw TwirpException.FromJson(errorBody)", indent)],
+            WireProtocol::Connect => vec![format!("{}Throw ConnectError.FromJson(errorBody)", indent)],
+        }
+    }
+
+    /// VB lines throwing on a non-2xx .NET 4.0 `HttpWebResponse` whose error
+    /// stream was empty, assuming `errorResp As HttpWebResponse` is already
+    /// in scope. There's no error-envelope JSON to parse here, so Twirp and
+    /// Connect mode each fall back to a generic error carrying the status
+    /// reason.
+    fn error_throw_lines_net40hwr_no_body(&self, indent: &str) -> Vec<String> {
+        match self.wire_protocol {
+            WireProtocol::Legacy => vec![format!(
+                "{}Throw New WebException($\"Request failed with status {{(CInt(errorResp.StatusCode))}} ({{errorResp.StatusDescription}})\")",
+                indent
+            )],
+            WireProtocol::Twirp => vec![format!(
+                "{}Throw New TwirpException(\"internal\", errorResp.StatusDescription, New Dictionary(Of String, String)())",
+                indent
+            )],
+            WireProtocol::Connect => vec![format!(
+                "{}Throw New ConnectError(ConnectCode.Internal, errorResp.StatusDescription)",
+                indent
+            )],
+        }
     }
 
     /// Split an RPC method name into (base_name, version_segment).
@@ -464,13 +2239,34 @@ impl CodeGenerator for VbNetGenerator {
             sections.push("".to_string());
         }
 
-        // Services (HTTP clients)
-        let services = self.generate_services(proto);
-        if !services.is_empty() {
-            sections.push(services);
+        // Twirp/Connect error type, shared by every service client in this file
+        if self.wire_protocol == WireProtocol::Twirp && !proto.services().is_empty() {
+            sections.push(self.generate_twirp_exception_class());
+            sections.push("".to_string());
+        }
+        if self.wire_protocol == WireProtocol::Connect && !proto.services().is_empty() {
+            sections.push(self.generate_connect_error_class());
             sections.push("".to_string());
         }
 
+        // Services (HTTP clients)
+        if self.generation_target.includes_client() {
+            let services = self.generate_services(proto);
+            if !services.is_empty() {
+                sections.push(services);
+                sections.push("".to_string());
+            }
+        }
+
+        // Server controller stubs
+        if self.generation_target.includes_server() {
+            let controllers = self.generate_server_controllers(proto);
+            if !controllers.is_empty() {
+                sections.push(controllers);
+                sections.push("".to_string());
+            }
+        }
+
         // Namespace end
         sections.push("End Namespace".to_string());
 
@@ -668,7 +2464,646 @@ mod tests {
         assert_eq!(generator.split_rpc_name_and_version("MethodV"), ("MethodV".to_string(), "v1".to_string())); // V without number
     }
 
-    #[test] 
+    #[test]
+    fn test_map_field_generates_dictionary() {
+        let map_type = ProtoType::map(ScalarType::String, ProtoType::Scalar(ScalarType::Int32)).unwrap();
+        assert_eq!(map_type.to_vb_type(None), "Dictionary(Of String, Integer)");
+
+        assert!(ProtoType::map(ScalarType::Double, ProtoType::Scalar(ScalarType::Int32)).is_err());
+    }
+
+    #[test]
+    fn test_oneof_generates_discriminator_and_nullable_properties() {
+        let oneof = ProtoOneofBuilder::default()
+            .name(Identifier::new("payload").unwrap())
+            .variants(vec![
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("text").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::String))
+                    .field_number(1)
+                    .build()
+                    .unwrap(),
+                ProtoFieldBuilder::default()
+                    .name(Identifier::new("number").unwrap())
+                    .field_type(ProtoType::Scalar(ScalarType::Int32))
+                    .field_number(2)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        let message = ProtoMessageBuilder::default()
+            .name(Identifier::new("Event").unwrap())
+            .oneofs(vec![oneof])
+            .build()
+            .unwrap();
+
+        let proto = ProtoFileBuilder::default()
+            .file_name("event.proto".to_string())
+            .build()
+            .unwrap();
+
+        let generator = VbNetGenerator::new(None, CompatibilityMode::Net45);
+        let code = generator.generate_message(&message, &proto, 1);
+
+        assert!(code.contains("Public Enum PayloadCase"));
+        assert!(code.contains("    Text"));
+        assert!(code.contains("    Number"));
+        assert!(code.contains("Public Property PayloadCase As PayloadCase"));
+        assert!(code.contains("<JsonProperty(\"text\")>"));
+        assert!(code.contains("Public Property Text As String"));
+    }
+
+    #[test]
+    fn test_twirp_wire_protocol_generates_routes_and_error_envelope() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_wire_protocol(None, CompatibilityMode::Net45, false, WireProtocol::Twirp);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Class TwirpException"));
+        assert!(code.contains("TwirpException.FromJson"));
+        assert!(code.contains("\"/twirp/helloworld.Greeter/SayHello\""));
+        assert!(!code.contains("/helloworld/say-hello/v1"));
+    }
+
+    #[test]
+    fn test_connect_wire_protocol_generates_routes_and_error_envelope() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_wire_protocol(None, CompatibilityMode::Net45, false, WireProtocol::Connect);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Enum ConnectCode"));
+        assert!(code.contains("Public Class ConnectError"));
+        assert!(code.contains("ConnectError.FromJson"));
+        assert!(code.contains("\"Connect-Protocol-Version\", \"1\""));
+        assert!(code.contains("\"/helloworld.Greeter/SayHello\""));
+        assert!(!code.contains("/helloworld/say-hello/v1"));
+    }
+
+    #[test]
+    fn test_streaming_rpc_honors_cancellation_between_reads() {
+        let stream_rpc = ProtoRpcBuilder::default()
+            .name(Identifier::new("ListUpdates").unwrap())
+            .input_type(ProtoType::Message {
+                name: "HelloRequest".to_string(),
+                package: None,
+            })
+            .output_type(ProtoType::Message {
+                name: "HelloReply".to_string(),
+                package: None,
+            })
+            .server_streaming(true)
+            .build()
+            .unwrap();
+
+        let service = ProtoServiceBuilder::default()
+            .name(Identifier::new("Greeter").unwrap())
+            .rpcs(vec![stream_rpc])
+            .build()
+            .unwrap();
+
+        let proto = ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .services(vec![service])
+            .build()
+            .unwrap();
+
+        let generator = VbNetGenerator::with_streaming(None, CompatibilityMode::Net45, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Async Function ListUpdatesAsync(request As HelloRequest, onNext As Action(Of HelloReply), cancellationToken As CancellationToken) As Task"));
+        assert!(code.contains("Do While Not reader.EndOfStream"));
+        assert!(code.contains("cancellationToken.ThrowIfCancellationRequested()"));
+    }
+
+    fn build_streaming_rpc_proto(name: &str, client_streaming: bool, server_streaming: bool) -> ProtoFile {
+        let rpc = ProtoRpcBuilder::default()
+            .name(Identifier::new(name).unwrap())
+            .input_type(ProtoType::Message {
+                name: "HelloRequest".to_string(),
+                package: None,
+            })
+            .output_type(ProtoType::Message {
+                name: "HelloReply".to_string(),
+                package: None,
+            })
+            .client_streaming(client_streaming)
+            .server_streaming(server_streaming)
+            .build()
+            .unwrap();
+
+        let service = ProtoServiceBuilder::default()
+            .name(Identifier::new("Greeter").unwrap())
+            .rpcs(vec![rpc])
+            .build()
+            .unwrap();
+
+        ProtoFileBuilder::default()
+            .file_name("helloworld.proto".to_string())
+            .package(Some(PackageName::new("helloworld").unwrap()))
+            .services(vec![service])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_client_streaming_rpc_buffers_requests_into_ndjson_net45() {
+        let proto = build_streaming_rpc_proto("Record", true, false);
+        let generator = VbNetGenerator::with_streaming(None, CompatibilityMode::Net45, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Async Function RecordAsync(requests As IEnumerable(Of HelloRequest), cancellationToken As CancellationToken) As Task(Of HelloReply)"));
+        assert!(code.contains("For Each item In requests"));
+        assert!(code.contains("sb.Append(JsonConvert.SerializeObject(item))"));
+        assert!(code.contains("sb.Append(vbLf)"));
+        assert!(code.contains("Return JsonConvert.DeserializeObject(Of HelloReply)(respJson)"));
+    }
+
+    #[test]
+    fn test_client_streaming_rpc_buffers_requests_into_ndjson_net40hwr() {
+        let proto = build_streaming_rpc_proto("Record", true, false);
+        let generator = VbNetGenerator::with_streaming(None, CompatibilityMode::Net40Hwr, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Function Record(requests As IEnumerable(Of HelloRequest)) As HelloReply"));
+        assert!(code.contains("For Each item In requests"));
+        assert!(code.contains("Dim data As Byte() = Encoding.UTF8.GetBytes(json)"));
+    }
+
+    #[test]
+    fn test_bidi_streaming_rpc_net45_streams_requests_and_responses() {
+        let proto = build_streaming_rpc_proto("Chat", true, true);
+        let generator = VbNetGenerator::with_streaming(None, CompatibilityMode::Net45, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Async Function ChatAsync(requests As IEnumerable(Of HelloRequest), onNext As Action(Of HelloReply), cancellationToken As CancellationToken) As Task"));
+        assert!(code.contains("sb.Append(JsonConvert.SerializeObject(item))"));
+        assert!(code.contains("Do While Not reader.EndOfStream"));
+    }
+
+    #[test]
+    fn test_bidi_streaming_rpc_net40hwr_throws_not_supported() {
+        let proto = build_streaming_rpc_proto("Chat", true, true);
+        let generator = VbNetGenerator::with_streaming(None, CompatibilityMode::Net40Hwr, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Sub Chat(requests As IEnumerable(Of HelloRequest), onNext As Action(Of HelloReply))"));
+        assert!(code.contains("Throw New NotSupportedException(\"Greeter.Chat is a bidirectional-streaming RPC"));
+    }
+
+    #[test]
+    fn test_compression_net45_gzips_requests_and_decompresses_responses() {
+        let proto = create_test_proto();
+        let generator =
+            VbNetGenerator::with_compression(None, CompatibilityMode::Net45, false, WireProtocol::Legacy, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("\"Accept-Encoding\", \"gzip, deflate\""));
+        assert!(code.contains("CompressGzip(Encoding.UTF8.GetBytes(json))"));
+        assert!(code.contains("New ByteArrayContent(requestBytes)"));
+        assert!(code.contains("content.Headers.ContentEncoding.Add(\"gzip\")"));
+        assert!(code.contains("Private Shared Function DecompressGzip(data As Byte()) As Byte()"));
+        assert!(code.contains("response.Content.Headers.ContentEncoding.Contains(\"gzip\")"));
+    }
+
+    #[test]
+    fn test_compression_net40hwr_sets_automatic_decompression_without_manual_helper() {
+        let proto = create_test_proto();
+        let generator =
+            VbNetGenerator::with_compression(None, CompatibilityMode::Net40Hwr, false, WireProtocol::Legacy, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("req.Headers.Add(\"Content-Encoding\", \"gzip\")"));
+        assert!(code.contains("req.AutomaticDecompression = DecompressionMethods.GZip Or DecompressionMethods.Deflate"));
+        assert!(code.contains("Private Shared Function CompressGzip(data As Byte()) As Byte()"));
+        assert!(!code.contains("Private Shared Function DecompressGzip"));
+    }
+
+    #[test]
+    fn test_serialization_format_protobuf_net45_uses_protobuf_net() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_serialization_format(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Protobuf,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("<ProtoContract()>"));
+        assert!(code.contains("<ProtoMember("));
+        assert!(!code.contains("<JsonProperty("));
+        assert!(code.contains("ProtoBuf.Serializer.Serialize(Of TReq)(reqStream, request)"));
+        assert!(code.contains("ProtoBuf.Serializer.Deserialize(Of TResp)(respStream)"));
+        assert!(code.contains("New MediaTypeHeaderValue(\"application/protobuf\")"));
+    }
+
+    #[test]
+    fn test_serialization_format_protobuf_net40hwr_uses_protobuf_net() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_serialization_format(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Protobuf,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("<ProtoContract()>"));
+        assert!(code.contains("req.ContentType = \"application/protobuf\""));
+        assert!(code.contains("ProtoBuf.Serializer.Serialize(Of TReq)(reqStream, request)"));
+        assert!(code.contains("ProtoBuf.Serializer.Deserialize(Of TResp)(protoStream)"));
+    }
+
+    #[test]
+    fn test_retry_net45_wraps_send_in_backoff_loop() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_retry(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            true,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000"));
+        assert!(code.contains("Dim attempt As Integer = 0"));
+        assert!(code.contains("IsRetryableStatusCode(CInt(response.StatusCode)) AndAlso attempt < _maxAttempts - 1"));
+        assert!(code.contains("GetRetryAfterDelayMs(response).GetValueOrDefault(ComputeBackoffDelayMs(attempt))"));
+        assert!(code.contains("Await Task.Delay(delayMs, cancellationToken).ConfigureAwait(False)"));
+        assert!(code.contains("Catch ex As HttpRequestException When attempt < _maxAttempts - 1"));
+    }
+
+    #[test]
+    fn test_retry_net40hwr_uses_thread_sleep_and_retry_after_header() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_retry(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            true,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Sub New(baseUrl As String, Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000)"));
+        assert!(code.contains("IsRetryableStatusCode(CInt(errorResp.StatusCode)) AndAlso attempt < _maxAttempts - 1"));
+        assert!(code.contains("errorResp.Headers(\"Retry-After\")"));
+        assert!(code.contains("Thread.Sleep(delayMs)"));
+        assert!(code.contains("Continue Do"));
+    }
+
+    #[test]
+    fn test_generation_target_server_emits_controller_base_without_client() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_generation_target(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Server,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Imports Microsoft.AspNetCore.Mvc"));
+        assert!(code.contains("Public MustInherit Class GreeterControllerBase"));
+        assert!(code.contains("Inherits ControllerBase"));
+        assert!(code.contains("<HttpPost(\"/helloworld/say-hello/v1\")>"));
+        assert!(code.contains("Public Async Function SayHelloEndpoint(<FromBody> request As HelloRequest) As Task(Of IActionResult)"));
+        assert!(code.contains("Public MustOverride Function SayHello(request As HelloRequest) As Task(Of HelloReply)"));
+        assert!(!code.contains("Public Class GreeterClient"));
+    }
+
+    #[test]
+    fn test_generation_target_both_emits_client_and_server() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_generation_target(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Both,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Class GreeterClient"));
+        assert!(code.contains("Public MustInherit Class GreeterControllerBase"));
+    }
+
+    #[test]
+    fn test_credential_mode_bearer_token_net45_awaits_token_provider_per_call() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::BearerToken,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Private ReadOnly _tokenProvider As Func(Of Task(Of String))"));
+        assert!(code.contains("Public Sub New(http As HttpClient, baseUrl As String, Optional tokenProvider As Func(Of Task(Of String)) = Nothing)"));
+        assert!(code.contains("_tokenProvider = tokenProvider"));
+        assert!(code.contains("Dim req As New HttpRequestMessage(HttpMethod.Post, url) With {.Content = content}"));
+        assert!(code.contains("Dim bearerToken As String = Await _tokenProvider().ConfigureAwait(False)"));
+        assert!(code.contains("req.Headers.Authorization = New AuthenticationHeaderValue(\"Bearer\", bearerToken)"));
+    }
+
+    #[test]
+    fn test_credential_mode_api_key_net45_sets_static_header_once() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::ApiKey,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Sub New(http As HttpClient, baseUrl As String, apiKey As String)"));
+        assert!(code.contains("If String.IsNullOrWhiteSpace(apiKey) Then Throw New ArgumentException(\"apiKey cannot be null or empty\")"));
+        assert!(code.contains("_http.DefaultRequestHeaders.Add(\"X-Api-Key\", apiKey)"));
+        assert!(!code.contains("_tokenProvider"));
+    }
+
+    #[test]
+    fn test_credential_mode_api_key_with_retry_net45_keeps_required_param_before_optional() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            true,
+            GenerationTarget::Client,
+            CredentialMode::ApiKey,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        // `apiKey` is required, so it must appear before the `Optional`
+        // retry parameters - VB.NET rejects a required parameter after an
+        // optional one (BC30202).
+        assert!(code.contains("Public Sub New(http As HttpClient, baseUrl As String, apiKey As String, Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000)"));
+    }
+
+    #[test]
+    fn test_credential_mode_bearer_token_net40hwr_sets_header_per_call() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::BearerToken,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Private ReadOnly _tokenProvider As Func(Of String)"));
+        assert!(code.contains("Public Sub New(baseUrl As String, Optional tokenProvider As Func(Of String) = Nothing)"));
+        assert!(code.contains("If _tokenProvider IsNot Nothing Then req.Headers(\"Authorization\") = \"Bearer \" & _tokenProvider()"));
+    }
+
+    #[test]
+    fn test_credential_mode_api_key_net40hwr_sets_header_per_call() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::ApiKey,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Private ReadOnly _apiKey As String"));
+        assert!(code.contains("Public Sub New(baseUrl As String, apiKey As String)"));
+        assert!(code.contains("_apiKey = apiKey"));
+        assert!(code.contains("req.Headers.Add(\"X-Api-Key\", _apiKey)"));
+    }
+
+    #[test]
+    fn test_credential_mode_api_key_with_retry_net40hwr_keeps_required_param_before_optional() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_credentials(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            true,
+            GenerationTarget::Client,
+            CredentialMode::ApiKey,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Sub New(baseUrl As String, apiKey As String, Optional maxAttempts As Integer = 3, Optional baseDelayMs As Integer = 200, Optional maxDelayMs As Integer = 5000)"));
+    }
+
+    #[test]
+    fn test_naming_config_type_override_renames_message_service_and_rpc() {
+        let proto = create_test_proto();
+        let naming = NamingConfig::new()
+            .with_type_override("HelloRequest", "HelloRequestDto")
+            .with_type_override("Greeter", "GreeterService")
+            .with_type_override("SayHello", "SayHelloRpc");
+        let generator = VbNetGenerator::with_naming_config(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            naming,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Public Class HelloRequestDto"));
+        assert!(code.contains("Public Class GreeterServiceClient"));
+        assert!(code.contains("Public Function SayHelloRpcAsync(request As HelloRequestDto) As Task(Of HelloReply)"));
+        assert!(!code.contains("Public Class HelloRequest\n"));
+    }
+
+    #[test]
+    fn test_naming_config_property_casing_controls_json_property_names() {
+        let proto = create_test_proto();
+        let naming = NamingConfig::new().with_property_casing(PropertyCasing::SnakeCase);
+        let generator = VbNetGenerator::with_naming_config(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            naming,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("<JsonProperty(\"name\")>"));
+        assert!(code.contains("<JsonProperty(\"message\")>"));
+    }
+
+    #[test]
+    fn test_naming_config_namespace_override_applies_per_package() {
+        let proto = create_test_proto();
+        let naming = NamingConfig::new().with_namespace_override("helloworld", "Acme.Greeting");
+        let generator = VbNetGenerator::with_naming_config(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            naming,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Namespace Acme.Greeting"));
+        assert!(!code.contains("Namespace Helloworld"));
+    }
+
+    #[test]
+    fn test_naming_config_explicit_namespace_flag_wins_over_override() {
+        let proto = create_test_proto();
+        let naming = NamingConfig::new().with_namespace_override("helloworld", "Acme.Greeting");
+        let generator = VbNetGenerator::with_naming_config(
+            Some("Explicit.Namespace".to_string()),
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            false,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            naming,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Namespace Explicit.Namespace"));
+    }
+
+    #[test]
+    fn test_naming_config_from_json_parses_all_fields() {
+        let value = serde_json::json!({
+            "type_overrides": {"HelloRequest": "HelloRequestDto"},
+            "property_casing": "pascal-case",
+            "namespace_overrides": {"helloworld": "Acme.Greeting"},
+        });
+        let naming = NamingConfig::from_json(&value).unwrap();
+
+        assert_eq!(naming.resolve_type_name("HelloRequest"), "HelloRequestDto");
+        assert_eq!(naming.resolve_type_name("HelloReply"), "HelloReply");
+        assert_eq!(naming.property_casing(), PropertyCasing::PascalCase);
+        assert_eq!(
+            naming.resolve_namespace(Some("helloworld"), "Helloworld".to_string()),
+            "Acme.Greeting"
+        );
+    }
+
+    #[test]
+    fn test_compression_threshold_net45_gates_compression_on_body_size() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_compression_threshold(
+            None,
+            CompatibilityMode::Net45,
+            false,
+            WireProtocol::Legacy,
+            true,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            NamingConfig::default(),
+            1024,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Dim compressRequest As Boolean = requestBytes.Length > 1024"));
+        assert!(code.contains("If compressRequest Then requestBytes = CompressGzip(requestBytes)"));
+        assert!(code.contains("If compressRequest Then content.Headers.ContentEncoding.Add(\"gzip\")"));
+    }
+
+    #[test]
+    fn test_compression_threshold_net40hwr_gates_content_encoding_header() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_compression_threshold(
+            None,
+            CompatibilityMode::Net40Hwr,
+            false,
+            WireProtocol::Legacy,
+            true,
+            SerializationFormat::Json,
+            false,
+            GenerationTarget::Client,
+            CredentialMode::None,
+            NamingConfig::default(),
+            512,
+        );
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(code.contains("Dim compressRequest As Boolean = data.Length > 512"));
+        assert!(code.contains("If compressRequest Then data = CompressGzip(data)"));
+        assert!(code.contains("If compressRequest Then req.Headers.Add(\"Content-Encoding\", \"gzip\")"));
+    }
+
+    #[test]
+    fn test_compression_threshold_zero_preserves_unconditional_compression() {
+        let proto = create_test_proto();
+        let generator = VbNetGenerator::with_compression(None, CompatibilityMode::Net45, false, WireProtocol::Legacy, true);
+        let code = generator.generate_code(&proto).unwrap();
+
+        assert!(!code.contains("compressRequest"));
+        assert!(code.contains("CompressGzip(Encoding.UTF8.GetBytes(json))"));
+    }
+
+    #[test]
     fn test_timeout_parameter_generation() {
         let proto = create_test_proto();
         
@@ -0,0 +1,192 @@
+//! `--watch` mode: re-run generation whenever a `.proto` under the watched
+//! root changes.
+//!
+//! An earlier revision of this module tried to route reloads through
+//! [`crate::registry::ProtoRegistry`] so only the files actually affected
+//! by an edit would be re-parsed and re-resolved. That registry-side
+//! plumbing (`watch`/`WatchHandle`/`ReloadEvent`/content hashing) never
+//! got wired into the CLI's `--watch` path and was dead code, so it was
+//! removed rather than left unreachable. `ProtoRegistry` is still the
+//! right tool for import-aware resolution (see `crate::scaffold` and
+//! `crate::check`'s validation pass); this module intentionally stays on
+//! the simpler full-replan model below until incremental, import-graph-
+//! aware invalidation is worth the complexity it adds here.
+use crate::check::PlannedFile;
+use crate::error::{Error, Result};
+use crate::types::{CompatibilityMode, CredentialMode, GenerationTarget, NamingConfig, SerializationFormat, WireProtocol};
+use crate::{plan_directory_csharp, plan_directory_rust, plan_directory_with_shared_utilities, Target};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// How long to wait after the last filesystem event before re-running
+/// generation, coalescing bursts (e.g. an editor's save-then-rename) into a
+/// single cycle.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Plan every output for the current `--proto` tree, exactly like the
+/// one-shot path in `main`, so each watch cycle stays consistent with a
+/// normal run.
+#[allow(clippy::too_many_arguments)]
+fn plan_all(
+    proto_root: &Path,
+    out_dir: &PathBuf,
+    namespace: Option<String>,
+    compat_mode: CompatibilityMode,
+    target: Target,
+    streaming: bool,
+    wire_protocol: WireProtocol,
+    enable_compression: bool,
+    serialization_format: SerializationFormat,
+    enable_retry: bool,
+    generation_target: GenerationTarget,
+    credential_mode: CredentialMode,
+    naming: NamingConfig,
+    compression_threshold_bytes: usize,
+) -> Result<Vec<PlannedFile>> {
+    let proto_files = crate::utils::find_proto_files(proto_root)?;
+    match target {
+        Target::Vbnet => plan_directory_with_shared_utilities(
+            proto_files,
+            out_dir,
+            namespace,
+            compat_mode,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode,
+            naming,
+            compression_threshold_bytes,
+        ),
+        Target::Rust => plan_directory_rust(&proto_files, out_dir, namespace),
+        Target::Csharp => plan_directory_csharp(&proto_files, out_dir, namespace, compat_mode),
+    }
+}
+
+fn is_proto_event(event: &notify::Result<notify::Event>) -> bool {
+    match event {
+        Ok(event) => event.paths.iter().any(|p| p.extension().and_then(|e| e.to_str()) == Some("proto")),
+        Err(_) => false,
+    }
+}
+
+/// Run `--watch`: after the caller's initial generation pass, keep
+/// regenerating affected outputs whenever a `.proto` under `proto_root` is
+/// created, modified, or deleted, until interrupted.
+///
+/// Regeneration itself re-plans the whole tree every cycle (VB.NET's shared
+/// utility namespace already depends on every sibling file, so a per-file
+/// diff wouldn't be safe), but only the outputs whose *content* actually
+/// changed since the last cycle are written and reported — an import graph
+/// for finer-grained invalidation can replace this once multi-file import
+/// resolution lands.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    proto_root: PathBuf,
+    out_dir: PathBuf,
+    namespace: Option<String>,
+    compat_mode: CompatibilityMode,
+    target: Target,
+    streaming: bool,
+    wire_protocol: WireProtocol,
+    enable_compression: bool,
+    serialization_format: SerializationFormat,
+    enable_retry: bool,
+    generation_target: GenerationTarget,
+    credential_mode: CredentialMode,
+    naming: NamingConfig,
+    compression_threshold_bytes: usize,
+    initial_contents: HashMap<PathBuf, String>,
+) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)
+        .map_err(|e| Error::validation_error(format!("failed to start proto watcher: {}", e)))?;
+    watcher
+        .watch(&proto_root, RecursiveMode::Recursive)
+        .map_err(|e| Error::validation_error(format!("failed to watch {}: {}", proto_root.display(), e)))?;
+
+    println!("Watching {} for .proto changes (Ctrl-C to stop)...", proto_root.display());
+
+    let mut last_contents = initial_contents;
+    loop {
+        // Block for the first event of this cycle.
+        let first = match rx.recv() {
+            Ok(event) => event,
+            Err(_) => return Ok(()), // watcher disconnected; exit quietly
+        };
+        if !is_proto_event(&first) {
+            continue;
+        }
+
+        // Debounce: keep draining events until the window goes quiet.
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(_) => continue,
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+
+        let planned = match plan_all(
+            &proto_root,
+            &out_dir,
+            namespace.clone(),
+            compat_mode,
+            target,
+            streaming,
+            wire_protocol,
+            enable_compression,
+            serialization_format,
+            enable_retry,
+            generation_target,
+            credential_mode,
+            naming.clone(),
+            compression_threshold_bytes,
+        ) {
+            Ok(planned) => planned,
+            Err(e) => {
+                eprintln!("Generation failed: {}", e);
+                continue;
+            }
+        };
+
+        let mut changed = Vec::new();
+        let mut current_contents = HashMap::new();
+        for file in &planned {
+            if last_contents.get(&file.path) != Some(&file.content) {
+                fs::create_dir_all(&out_dir)?;
+                fs::write(&file.path, &file.content)?;
+                changed.push(file.path.clone());
+            }
+            current_contents.insert(file.path.clone(), file.content.clone());
+        }
+        let removed: Vec<PathBuf> = last_contents
+            .keys()
+            .filter(|path| !current_contents.contains_key(*path))
+            .cloned()
+            .collect();
+
+        if changed.is_empty() && removed.is_empty() {
+            println!("No output changes.");
+        } else {
+            println!("Regenerated {} file(s):", changed.len());
+            for path in &changed {
+                println!("  {}", path.display());
+            }
+            if !removed.is_empty() {
+                println!("No longer generated (left on disk):");
+                for path in &removed {
+                    println!("  {}", path.display());
+                }
+            }
+        }
+
+        last_contents = current_contents;
+    }
+}
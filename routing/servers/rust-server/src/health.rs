@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::watch;
+use tokio_stream::Stream;
+use tonic::{Request, Response, Status};
+
+pub mod grpc_health_v1 {
+    tonic::include_proto!("grpc.health.v1");
+}
+
+use grpc_health_v1::health_check_response::ServingStatus;
+use grpc_health_v1::health_server::{Health, HealthServer};
+use grpc_health_v1::{HealthCheckRequest, HealthCheckResponse};
+
+struct Inner {
+    /// One `watch` channel per service name; the empty string `""` is the
+    /// overall server health, per the `grpc.health.v1.Health` convention.
+    statuses: Mutex<HashMap<String, watch::Sender<ServingStatus>>>,
+}
+
+/// Handle for flipping a service's serving status, e.g. to `NOT_SERVING`
+/// during graceful shutdown.
+#[derive(Clone)]
+pub struct HealthReporter {
+    inner: Arc<Inner>,
+}
+
+impl HealthReporter {
+    pub fn set_serving(&self, service: &str) {
+        self.set_status(service, ServingStatus::Serving);
+    }
+
+    pub fn set_not_serving(&self, service: &str) {
+        self.set_status(service, ServingStatus::NotServing);
+    }
+
+    fn set_status(&self, service: &str, status: ServingStatus) {
+        let mut statuses = self.inner.statuses.lock().unwrap();
+        match statuses.get(service) {
+            Some(tx) => {
+                let _ = tx.send(status);
+            }
+            None => {
+                let (tx, _rx) = watch::channel(status);
+                statuses.insert(service.to_string(), tx);
+            }
+        }
+    }
+}
+
+pub struct HealthService {
+    inner: Arc<Inner>,
+}
+
+/// Build a `grpc.health.v1.Health` server plus a [`HealthReporter`] handle,
+/// with the overall server (`""`) defaulted to `SERVING`.
+pub fn health_service() -> (HealthServer<HealthService>, HealthReporter) {
+    let mut initial = HashMap::new();
+    initial.insert(String::new(), watch::channel(ServingStatus::Serving).0);
+
+    let inner = Arc::new(Inner {
+        statuses: Mutex::new(initial),
+    });
+
+    (
+        HealthServer::new(HealthService {
+            inner: inner.clone(),
+        }),
+        HealthReporter { inner },
+    )
+}
+
+#[tonic::async_trait]
+impl Health for HealthService {
+    type WatchStream = Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, Status>> + Send>>;
+
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        let service = request.into_inner().service;
+        let statuses = self.inner.statuses.lock().unwrap();
+        let status = statuses
+            .get(&service)
+            .map(|tx| *tx.borrow())
+            .unwrap_or(ServingStatus::ServiceUnknown);
+
+        Ok(Response::new(HealthCheckResponse {
+            status: status as i32,
+        }))
+    }
+
+    async fn watch(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let service = request.into_inner().service;
+        let mut rx = {
+            let mut statuses = self.inner.statuses.lock().unwrap();
+            let tx = statuses
+                .entry(service)
+                .or_insert_with(|| watch::channel(ServingStatus::ServiceUnknown).0);
+            tx.subscribe()
+        };
+
+        let stream = async_stream::stream! {
+            let mut last = *rx.borrow();
+            yield Ok(HealthCheckResponse { status: last as i32 });
+
+            while rx.changed().await.is_ok() {
+                let current = *rx.borrow();
+                if current != last {
+                    last = current;
+                    yield Ok(HealthCheckResponse { status: current as i32 });
+                }
+            }
+        };
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
@@ -1,8 +1,16 @@
 use prometheus::{Encoder, Gauge, HistogramVec, IntCounterVec, TextEncoder};
 use std::net::SocketAddr;
-use std::time::Instant;
+use std::sync::Arc;
 use tonic::{transport::Server, Request, Response, Status};
 
+mod health;
+mod metrics_layer;
+mod reflection;
+mod tls;
+use metrics_layer::MetricsLayer;
+use reflection::ReflectionSettings;
+use tls::TlsSettings;
+
 pub mod helloworld {
     tonic::include_proto!("helloworld");
 }
@@ -14,7 +22,7 @@ lazy_static::lazy_static! {
     static ref REQUESTS_TOTAL: IntCounterVec = prometheus::register_int_counter_vec!(
         "grpc_requests_total",
         "Total number of gRPC requests",
-        &["method", "status"]
+        &["method", "status", "encoding"]
     ).unwrap();
 
     static ref REQUEST_DURATION: HistogramVec = prometheus::register_histogram_vec!(
@@ -32,6 +40,55 @@ lazy_static::lazy_static! {
 const SERVER_NAME: &str = "Rust Server";
 const VERSION: &str = "v2";
 
+/// Compression settings for the `Greeter` service, sourced from CLI/env.
+struct CompressionSettings {
+    codecs: Vec<tonic::codec::CompressionEncoding>,
+}
+
+/// Settings for gRPC-Web support (`tonic-web`), sourced from CLI/env.
+struct WebSettings {
+    enabled: bool,
+}
+
+impl WebSettings {
+    /// Read `GRPC_WEB_ENABLED` (default disabled; browser clients that need
+    /// to call `SayHello` directly over HTTP/1.1 opt in explicitly).
+    fn from_env() -> Self {
+        let enabled = std::env::var("GRPC_WEB_ENABLED")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Self { enabled }
+    }
+}
+
+impl CompressionSettings {
+    /// Read `GRPC_COMPRESSION_CODECS` (comma-separated `gzip`/`zstd`,
+    /// default both).
+    fn from_env() -> Self {
+        let codecs = std::env::var("GRPC_COMPRESSION_CODECS")
+            .ok()
+            .map(|raw| {
+                raw.split(',')
+                    .filter_map(|codec| match codec.trim() {
+                        "gzip" => Some(tonic::codec::CompressionEncoding::Gzip),
+                        "zstd" => Some(tonic::codec::CompressionEncoding::Zstd),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|codecs| !codecs.is_empty())
+            .unwrap_or_else(|| {
+                vec![
+                    tonic::codec::CompressionEncoding::Gzip,
+                    tonic::codec::CompressionEncoding::Zstd,
+                ]
+            });
+
+        Self { codecs }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct GreeterService {}
 
@@ -41,13 +98,6 @@ impl Greeter for GreeterService {
         &self,
         request: Request<HelloRequest>,
     ) -> Result<Response<HelloReply>, Status> {
-        let start = Instant::now();
-
-        ACTIVE_CONNECTIONS.inc();
-        let _guard = scopeguard::guard((), |_| {
-            ACTIVE_CONNECTIONS.dec();
-        });
-
         let name = request.into_inner().name;
         println!("Received request from: {}", name);
 
@@ -61,14 +111,6 @@ impl Greeter for GreeterService {
             architecture: arch,
         };
 
-        // Record metrics
-        REQUESTS_TOTAL
-            .with_label_values(&["SayHello", "success"])
-            .inc();
-        REQUEST_DURATION
-            .with_label_values(&["SayHello"])
-            .observe(start.elapsed().as_secs_f64());
-
         Ok(Response::new(reply))
     }
 }
@@ -86,7 +128,7 @@ async fn health_handler() -> Result<&'static str, hyper::http::Error> {
     Ok("OK")
 }
 
-async fn run_metrics_server(addr: SocketAddr) {
+async fn run_metrics_server(addr: SocketAddr, tls_acceptor: Option<tokio_rustls::TlsAcceptor>) {
     use hyper::server::conn::http1;
     use hyper::service::service_fn;
     use hyper::{body::Incoming, Request as HyperRequest, Response as HyperResponse};
@@ -125,16 +167,38 @@ async fn run_metrics_server(addr: SocketAddr) {
     }
 
     let listener = TcpListener::bind(addr).await.unwrap();
-    println!("Metrics server listening on {}", addr);
+    println!(
+        "Metrics server listening on {} ({})",
+        addr,
+        if tls_acceptor.is_some() { "tls" } else { "plaintext" }
+    );
 
     loop {
         let (stream, _) = listener.accept().await.unwrap();
-        let io = TokioIo::new(stream);
+        let tls_acceptor = tls_acceptor.clone();
+
         tokio::task::spawn(async move {
-            if let Err(err) = http1::Builder::new()
-                .serve_connection(io, service_fn(handle_request))
-                .await
-            {
+            let serve_result = if let Some(tls_acceptor) = tls_acceptor {
+                match tls_acceptor.accept(stream).await {
+                    Ok(tls_stream) => {
+                        let io = TokioIo::new(tls_stream);
+                        http1::Builder::new()
+                            .serve_connection(io, service_fn(handle_request))
+                            .await
+                    }
+                    Err(err) => {
+                        eprintln!("TLS handshake failed: {:?}", err);
+                        return;
+                    }
+                }
+            } else {
+                let io = TokioIo::new(stream);
+                http1::Builder::new()
+                    .serve_connection(io, service_fn(handle_request))
+                    .await
+            };
+
+            if let Err(err) = serve_result {
                 eprintln!("Error serving connection: {:?}", err);
             }
         });
@@ -155,21 +219,70 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let metrics_addr: SocketAddr = "0.0.0.0:9092".parse()?;
 
     let greeter = GreeterService::default();
+    let tls_settings = TlsSettings::from_env();
+    let compression = CompressionSettings::from_env();
+    let reflection_settings = ReflectionSettings::from_env();
+    let web_settings = WebSettings::from_env();
+    println!("gRPC compression codecs: {:?}", compression.codecs);
+    println!(
+        "gRPC reflection: {}, gRPC-Web: {}",
+        if reflection_settings.enabled { "enabled" } else { "disabled" },
+        if web_settings.enabled { "enabled" } else { "disabled" }
+    );
 
-    // Start metrics server in background
+    let mut greeter_server = GreeterServer::new(greeter);
+    for codec in &compression.codecs {
+        greeter_server = greeter_server.accept_compressed(*codec).send_compressed(*codec);
+    }
+
+    // Start metrics server in background, TLS-wrapped when enabled
+    let metrics_tls_acceptor = if tls_settings.enabled {
+        let rustls_config = tls::build_server_config(&tls_settings)?;
+        Some(tokio_rustls::TlsAcceptor::from(Arc::new(rustls_config)))
+    } else {
+        None
+    };
     tokio::spawn(async move {
-        run_metrics_server(metrics_addr).await;
+        run_metrics_server(metrics_addr, metrics_tls_acceptor).await;
     });
 
-    println!("gRPC server listening on {}", grpc_addr);
+    println!(
+        "gRPC server listening on {} ({})",
+        grpc_addr,
+        if tls_settings.enabled { "tls" } else { "plaintext" }
+    );
+
+    let mut server_builder = Server::builder()
+        .accept_http1(web_settings.enabled)
+        .layer(MetricsLayer::default())
+        .layer(tower_http::cors::CorsLayer::permissive())
+        .layer(tonic_web::GrpcWebLayer::new());
+    if tls_settings.enabled {
+        let rustls_config = tls::build_server_config(&tls_settings)?;
+        let tls_config = tonic::transport::ServerTlsConfig::new().rustls_server_config(rustls_config);
+        server_builder = server_builder.tls_config(tls_config)?;
+    }
+
+    let (health_server, health_reporter) = health::health_service();
+    health_reporter.set_serving("helloworld.Greeter");
+
+    let mut router = server_builder
+        .add_service(greeter_server)
+        .add_service(health_server);
+    if reflection_settings.enabled {
+        router = router.add_service(reflection::reflection_service()?);
+    }
 
     // Start gRPC server with graceful shutdown
-    Server::builder()
-        .add_service(GreeterServer::new(greeter))
-        .serve_with_shutdown(grpc_addr, async {
+    router
+        .serve_with_shutdown(grpc_addr, async move {
             tokio::signal::ctrl_c()
                 .await
                 .expect("failed to listen for shutdown signal");
+            // Flip health to NOT_SERVING so load balancers stop routing new
+            // requests before the server actually stops accepting them.
+            health_reporter.set_not_serving("");
+            health_reporter.set_not_serving("helloworld.Greeter");
             println!("Shutting down gracefully...");
         })
         .await?;
@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use http::{Request, Response};
+use tonic::Code;
+use tower::{Layer, Service};
+
+use crate::{ACTIVE_CONNECTIONS, REQUESTS_TOTAL, REQUEST_DURATION};
+
+/// A `tower::Layer` that records `grpc_requests_total`,
+/// `grpc_request_duration_seconds`, and `grpc_active_connections` for every
+/// RPC passing through the server, so individual service implementations no
+/// longer need to instrument themselves by hand.
+#[derive(Clone, Default)]
+pub struct MetricsLayer;
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        // The method label from the URI path, e.g. "/helloworld.Greeter/SayHello".
+        let method = request.uri().path().to_string();
+        let encoding = request
+            .headers()
+            .get("grpc-encoding")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("identity")
+            .to_string();
+        let start = Instant::now();
+
+        ACTIVE_CONNECTIONS.inc();
+        let mut inner = self.inner.clone();
+
+        Box::pin(async move {
+            let _guard = scopeguard::guard((), |_| {
+                ACTIVE_CONNECTIONS.dec();
+            });
+
+            let response = inner.call(request).await;
+            let status_label = response
+                .as_ref()
+                .map(status_label_from_response)
+                .unwrap_or_else(|_| "internal".to_string());
+
+            REQUESTS_TOTAL
+                .with_label_values(&[&method, &status_label, &encoding])
+                .inc();
+            REQUEST_DURATION
+                .with_label_values(&[&method])
+                .observe(start.elapsed().as_secs_f64());
+
+            response
+        })
+    }
+}
+
+/// Derive the Prometheus status label from a response's `grpc-status`
+/// trailer/header, mapping the numeric `tonic::Code` to its lowercase name
+/// (e.g. `ok`, `invalid_argument`, `internal`).
+fn status_label_from_response<B>(response: &Response<B>) -> String {
+    let code = response
+        .headers()
+        .get("grpc-status")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i32>().ok())
+        .map(Code::from_i32)
+        .unwrap_or(Code::Ok);
+
+    code_label(code)
+}
+
+fn code_label(code: Code) -> String {
+    match code {
+        Code::Ok => "ok",
+        Code::Cancelled => "cancelled",
+        Code::Unknown => "unknown",
+        Code::InvalidArgument => "invalid_argument",
+        Code::DeadlineExceeded => "deadline_exceeded",
+        Code::NotFound => "not_found",
+        Code::AlreadyExists => "already_exists",
+        Code::PermissionDenied => "permission_denied",
+        Code::ResourceExhausted => "resource_exhausted",
+        Code::FailedPrecondition => "failed_precondition",
+        Code::Aborted => "aborted",
+        Code::OutOfRange => "out_of_range",
+        Code::Unimplemented => "unimplemented",
+        Code::Internal => "internal",
+        Code::Unavailable => "unavailable",
+        Code::DataLoss => "data_loss",
+        Code::Unauthenticated => "unauthenticated",
+    }
+    .to_string()
+}
@@ -0,0 +1,35 @@
+use tonic_reflection::server::v1::{Builder, ServerReflection, ServerReflectionServer};
+
+/// Settings for the `grpc.reflection.v1` service, sourced from CLI/env.
+#[derive(Debug, Clone)]
+pub struct ReflectionSettings {
+    pub enabled: bool,
+}
+
+impl ReflectionSettings {
+    /// Read `GRPC_REFLECTION_ENABLED` (default disabled; opt in explicitly
+    /// for environments where clients need to discover the service schema,
+    /// e.g. local development with `grpcurl`).
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("GRPC_REFLECTION_ENABLED")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Self { enabled }
+    }
+}
+
+/// Encoded `FileDescriptorSet` covering the `helloworld` and
+/// `grpc.health.v1` packages, emitted by the build script alongside the
+/// generated protobuf code so `grpcurl`/Postman can discover `Greeter`
+/// without the original `.proto` sources.
+const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("rust_server_descriptor");
+
+/// Build the `grpc.reflection.v1` server reflection service.
+pub fn reflection_service(
+) -> Result<ServerReflectionServer<impl ServerReflection>, Box<dyn std::error::Error>> {
+    Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .build_v1()
+        .map_err(Into::into)
+}
@@ -0,0 +1,71 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use rustls_pemfile::{certs, private_key};
+
+/// TLS settings for the gRPC and metrics listeners, sourced from CLI/env.
+#[derive(Debug, Clone)]
+pub struct TlsSettings {
+    pub enabled: bool,
+    pub cert_path: Option<PathBuf>,
+    pub key_path: Option<PathBuf>,
+    /// Optional client CA bundle enabling mutual TLS.
+    pub client_ca_path: Option<PathBuf>,
+}
+
+impl TlsSettings {
+    /// Read TLS settings from environment variables:
+    /// `TLS_ENABLED`, `TLS_CERT_PATH`, `TLS_KEY_PATH`, `TLS_CLIENT_CA_PATH`.
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("TLS_ENABLED")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+
+        Self {
+            enabled,
+            cert_path: std::env::var("TLS_CERT_PATH").ok().map(PathBuf::from),
+            key_path: std::env::var("TLS_KEY_PATH").ok().map(PathBuf::from),
+            client_ca_path: std::env::var("TLS_CLIENT_CA_PATH").ok().map(PathBuf::from),
+        }
+    }
+}
+
+/// Build a `rustls::ServerConfig` from the configured cert/key/client-CA
+/// paths, with ALPN protocols set for both HTTP/2 (gRPC) and HTTP/1.1
+/// (metrics) so the same config can back either listener.
+pub fn build_server_config(settings: &TlsSettings) -> Result<ServerConfig, Box<dyn std::error::Error>> {
+    let cert_path = settings
+        .cert_path
+        .as_ref()
+        .ok_or("TLS_CERT_PATH must be set when TLS is enabled")?;
+    let key_path = settings
+        .key_path
+        .as_ref()
+        .ok_or("TLS_KEY_PATH must be set when TLS is enabled")?;
+
+    let cert_chain = certs(&mut BufReader::new(File::open(cert_path)?)).collect::<Result<Vec<_>, _>>()?;
+    let key = private_key(&mut BufReader::new(File::open(key_path)?))?
+        .ok_or("no private key found in TLS_KEY_PATH")?;
+
+    let mut config = if let Some(ca_path) = &settings.client_ca_path {
+        let mut roots = RootCertStore::empty();
+        for cert in certs(&mut BufReader::new(File::open(ca_path)?)) {
+            roots.add(cert?)?;
+        }
+        let verifier = WebPkiClientVerifier::builder(Arc::new(roots)).build()?;
+        ServerConfig::builder()
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(cert_chain, key)?
+    } else {
+        ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(cert_chain, key)?
+    };
+
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}